@@ -244,14 +244,22 @@ cfg_alloc! {
 #[cfg_attr(all(docs, feature = "docs"), doc(cfg(feature = "alloc")))]
 pub mod arc;
 
+pub mod array;
+
 #[cfg(feature = "alloc")]
 #[cfg_attr(all(docs, feature = "docs"), doc(cfg(feature = "alloc")))]
 pub mod boxed;
 
 pub mod bytes;
 
+#[cfg(feature = "alloc")]
+#[cfg_attr(all(docs, feature = "docs"), doc(cfg(feature = "alloc")))]
+pub mod c_alloc;
+
 #[doc(inline)]
 pub use self::c_char_module::c_char;
+#[doc(inline)]
+pub use self::c_char_module::c_char16;
 #[path = "c_char.rs"]
 mod c_char_module;
 
@@ -264,6 +272,8 @@ pub mod closure;
 #[path = "dyn_traits/_mod.rs"]
 pub mod dyn_traits;
 
+pub mod foreign_box;
+
 #[cfg(feature = "futures")]
 #[cfg_attr(all(docs, feature = "nightly"), doc(cfg(feature = "futures")))]
 #[doc(no_inline)]
@@ -271,10 +281,28 @@ pub use dyn_traits::futures;
 
 pub mod libc;
 
+#[cfg(feature = "alloc")]
+#[cfg_attr(all(docs, feature = "docs"), doc(cfg(feature = "alloc")))]
+pub mod map;
+
+pub mod net;
+
 pub mod option;
 
+#[cfg(feature = "std")]
+#[cfg_attr(all(docs, feature = "docs"), doc(cfg(feature = "std")))]
+pub mod path;
+
 pub mod ptr;
 
+pub mod range;
+
+pub mod result;
+
+#[cfg(all(feature = "serde", feature = "alloc"))]
+#[cfg_attr(all(docs, feature = "nightly"), doc(cfg(feature = "serde")))]
+pub mod serde_transport;
+
 pub mod slice;
 
 #[cfg(feature = "stabby")]
@@ -285,6 +313,12 @@ pub mod stabby;
 #[path = "string/_mod.rs"]
 pub mod string;
 
+#[cfg(feature = "alloc")]
+#[cfg_attr(all(docs, feature = "docs"), doc(cfg(feature = "alloc")))]
+pub mod testing;
+
+pub mod time;
+
 #[doc(no_inline)]
 pub use tuple::*;
 
@@ -300,6 +334,8 @@ cfg_alloc! {
 #[cfg(feature = "alloc")]
 pub mod vec;
 
+pub mod wchar_p;
+
 #[doc(inline)]
 pub use layout::impls::c_int;
 
@@ -309,6 +345,10 @@ pub mod prelude {
     #[doc(no_inline)]
     pub use crate::layout::ReprC;
     pub mod char_p {
+        #[doc(no_inline)]
+        pub use crate::char_p::char_p_array as Array;
+        #[doc(no_inline)]
+        pub use crate::char_p::char_p_bytes as Bytes;
         #[doc(no_inline)]
         pub use crate::char_p::char_p_raw as Raw;
         #[doc(no_inline)]
@@ -317,6 +357,7 @@ pub mod prelude {
             #[doc(no_inline)]
             pub use crate::char_p::{
                 char_p_boxed as Box,
+                char_p_boxed_array as BoxedArray,
                 new,
             };
         }
@@ -331,9 +372,20 @@ pub mod prelude {
         cfg_alloc! {
             #[doc(no_inline)]
             pub use crate::slice::slice_boxed as Box;
+            #[doc(no_inline)]
+            pub use crate::slice::slice_arc as Arc;
         }
     }
     pub mod repr_c {
+        #[doc(no_inline)]
+        pub use crate::{
+            array::{ArrayString, ArrayVec},
+            foreign_box::{ForeignBox, ForeignBoxWithCtx},
+            net::{IpAddr, SocketAddr},
+            range::Range,
+            result::Result,
+            time::Duration,
+        };
         cfg_alloc! {
             #[doc(no_inline)]
             pub use crate::{
@@ -342,8 +394,23 @@ pub mod prelude {
                 string::String,
                 vec::Vec,
                 option::TaggedOption,
+                map::BTreeMap,
             };
         }
+        cfg_std! {
+            #[doc(no_inline)]
+            pub use crate::{
+                map::HashMap,
+                path::PathBuf,
+                time::SystemTime,
+            };
+        }
+    }
+    cfg_std! {
+        pub mod path {
+            #[doc(no_inline)]
+            pub use crate::path::path_ref as Ref;
+        }
     }
     pub mod str {
         #[doc(no_inline)]
@@ -353,7 +420,29 @@ pub mod prelude {
         };
         cfg_alloc! {
             #[doc(no_inline)]
-            pub use crate::string::str_boxed as Box;
+            pub use crate::string::{
+                str_arc as Arc,
+                str_boxed as Box,
+            };
+        }
+    }
+    pub mod str16 {
+        #[doc(no_inline)]
+        pub use crate::string::str16_ref as Ref;
+        cfg_alloc! {
+            #[doc(no_inline)]
+            pub use crate::string::str16_boxed as Box;
+        }
+    }
+    pub mod wchar_p {
+        #[doc(no_inline)]
+        pub use crate::wchar_p::wchar_p_ref as Ref;
+        cfg_alloc! {
+            #[doc(no_inline)]
+            pub use crate::wchar_p::{
+                new,
+                wchar_p_boxed as Box,
+            };
         }
     }
 
@@ -461,6 +550,13 @@ mod __ {
     pub use crate::layout::ConcreteReprC;
     pub use crate::layout::OpaqueKind;
     pub use crate::layout::ReprC;
+    #[cfg(feature = "alloc")]
+    pub use crate::layout::{
+        FieldDescriptor,
+        TypeDescriptor,
+        TypeKind,
+        VariantDescriptor,
+    };
     pub use crate::prelude::*;
     pub use crate::ptr;
 
@@ -504,6 +600,8 @@ mod __ {
     #[cfg(feature = "headers")]
     pub use crate::headers::languages::StructField;
     #[cfg(feature = "headers")]
+    pub use crate::headers::languages::VariantNaming;
+    #[cfg(feature = "headers")]
     pub use crate::headers::languages::{self};
     #[cfg(feature = "headers")]
     pub use crate::inventory;
@@ -68,6 +68,15 @@
 //!
 //! In either of these situations, you may want to directly target [`ThinArc<T>`] instead.
 //!
+//! # Weak references
+//!
+//! A [`ThinWeak<T>`], obtained through [`ThinArc::downgrade()`], is the FFI-safe counterpart of a
+//! standard-library [`Weak<T>`][::alloc::sync::Weak]: it does not keep the `T` alive, and needs to
+//! be [`upgrade()`][ThinWeak::upgrade]d to access it.
+//!
+//! For some opaque `T`, the [`ffi_export_arc_helpers!`][crate::ffi_export_arc_helpers] macro
+//! exports the C functions with which to manage both kinds of references from the C side.
+//!
 //! [tr]: [`FitForCArc`]
 
 use_prelude!();
@@ -123,6 +132,12 @@ impl<T> ThinArc<T> {
         Self(unsafe { ptr::NonNull::new_unchecked(ptr.cast_mut()) }.into())
     }
 
+    /// See [`rust::Arc<T>::downgrade()`].
+    #[inline]
+    pub fn downgrade(this: &Self) -> ThinWeak<T> {
+        this.with_rust(rust::Arc::downgrade).into()
+    }
+
     /// Morally, a <code>\&[ThinArc\<T\>][`ThinArc`] -> \&[Arc\<T\>][`rust::Arc`]</code> conversion.
     ///
     /// For lifetime reasons, this is exposed as a scoped / callback / CPS API.
@@ -190,9 +205,218 @@ impl<T: fmt::Debug> fmt::Debug for ThinArc<T> {
     }
 }
 
+ReprC! {
+    /// An FFI-safe representation of a standard-library `Weak<T>`, as a thin pointer to its
+    /// (maybe dropped) `T`.
+    ///
+    /// Since the `T` may have been dropped, the pointer is not to be dereferenced (from C
+    /// either), but [`upgrade()`][ThinWeak::upgrade]d to a [`ThinArc<T>`] instead.
+    ///
+    /// A dangling `Weak<T>` (_e.g._, `Weak::new()`), which points to no allocation whatsoever
+    /// (and which thus never upgrades), is represented by
+    /// [`NonNull::<T>::dangling()`][::core::ptr::NonNull::dangling] (_i.e._, `(T *)alignof(T)`),
+    /// rather than by the (unaligned) sentinel of the standard library.
+    #[repr(transparent)]
+    pub
+    struct ThinWeak[T] (
+        ptr::NonNullOwned<T>,
+    );
+}
+
+impl<T> From<rust::Weak<T>> for ThinWeak<T> {
+    #[inline]
+    fn from(weak: rust::Weak<T>) -> ThinWeak<T> {
+        if rust::Weak::ptr_eq(&weak, &rust::Weak::new()) {
+            return Self(ptr::NonNull::dangling().into());
+        }
+        let raw = rust::Weak::into_raw(weak);
+        Self(
+            ptr::NonNull::new(raw.cast_mut())
+                .expect("a non-dangling `Weak` points into its allocation")
+                .into(),
+        )
+    }
+}
+
+impl<T> ThinWeak<T> {
+    /// Whether `self` stands for a dangling `Weak<T>` (see [the type docs][ThinWeak]).
+    #[inline]
+    fn is_dangling(self: &'_ ThinWeak<T>) -> bool {
+        ::core::ptr::eq(self.0.as_ptr(), ptr::NonNull::<T>::dangling().as_ptr())
+    }
+
+    /// Safety: the returned `Weak<T>` is to be considered as owning the
+    /// reference `self` stands for.
+    #[inline]
+    unsafe fn to_rust(self: &'_ ThinWeak<T>) -> rust::Weak<T> {
+        if self.is_dangling() {
+            rust::Weak::new()
+        } else {
+            unsafe { rust::Weak::from_raw(self.0.as_ptr()) }
+        }
+    }
+
+    #[inline]
+    pub fn into(self: ThinWeak<T>) -> rust::Weak<T> {
+        unsafe { mem::ManuallyDrop::new(self).to_rust() }
+    }
+
+    /// See [`rust::Weak<T>::upgrade()`].
+    #[inline]
+    pub fn upgrade(self: &'_ ThinWeak<T>) -> Option<ThinArc<T>> {
+        self.with_rust(|weak| weak.upgrade().map(ThinArc::from))
+    }
+
+    /// See [`rust::Weak<T>::strong_count()`].
+    #[inline]
+    pub fn strong_count(self: &'_ ThinWeak<T>) -> usize {
+        self.with_rust(rust::Weak::strong_count)
+    }
+
+    /// Morally, a <code>\&[ThinWeak\<T\>][`ThinWeak`] -> \&[Weak\<T\>][`rust::Weak`]</code>
+    /// conversion.
+    ///
+    /// For lifetime reasons, this is exposed as a scoped / callback / CPS API.
+    #[inline]
+    pub fn with_rust<R>(
+        &self,
+        scope: impl FnOnce(&rust::Weak<T>) -> R,
+    ) -> R {
+        let yield_ = scope;
+        let weak: &rust::Weak<T> = &*mem::ManuallyDrop::new(unsafe { self.to_rust() });
+        yield_(weak)
+    }
+}
+
+impl<T> Drop for ThinWeak<T> {
+    #[inline]
+    fn drop(self: &'_ mut ThinWeak<T>) {
+        drop::<rust::Weak<T>>(unsafe { self.to_rust() });
+    }
+}
+
+unsafe impl<T> Send for ThinWeak<T> where rust::Weak<T>: Send {}
+
+unsafe impl<T> Sync for ThinWeak<T> where rust::Weak<T>: Sync {}
+
+impl<T> Clone for ThinWeak<T> {
+    #[inline]
+    fn clone(self: &'_ Self) -> Self {
+        self.with_rust(rust::Weak::clone).into()
+    }
+}
+
+impl<T> fmt::Debug for ThinWeak<T> {
+    fn fmt(
+        self: &'_ Self,
+        fmt: &'_ mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        fmt.write_str("(Weak)")
+    }
+}
+
+/// Exports, for the given (typically opaque) `T`, the `extern "C"` functions
+/// needed to manage its [`repr_c::Arc`][`Arc`] and [`ThinWeak`] references
+/// from C.
+///
+/// Given `prefix: T`, the following functions are
+/// [`#[ffi_export]`][crate::ffi_export]ed:
+///
+///   - `prefix_retain(&arc)`, returning a new strong reference,
+///   - `prefix_release(arc)`, giving back a strong reference,
+///   - `prefix_downgrade(&arc)`, returning a new weak reference,
+///   - `prefix_weak_retain(&weak)`, returning a new weak reference,
+///   - `prefix_weak_release(weak)`, giving back a weak reference,
+///   - `prefix_weak_upgrade(&weak)`, returning a new strong reference, or
+///     `NULL` if the `T` has already been dropped.
+///
+/// ```rust
+/// use ::safer_ffi::prelude::*;
+///
+/// #[derive_ReprC]
+/// #[repr(opaque)]
+/// pub struct Session {
+///     /* … */
+/// }
+///
+/// ::safer_ffi::ffi_export_arc_helpers!(session: Session);
+/// ```
+#[macro_export]
+#[cfg_attr(rustfmt, rustfmt::skip)]
+macro_rules! ffi_export_arc_helpers {(
+    $prefix:ident : $T:ty $(,)?
+) => ($crate::paste::item! {
+    const _: () = {
+        type ThinArc = $crate::arc::ThinArc<$T>;
+        type ThinWeak = $crate::arc::ThinWeak<$T>;
+
+        /// Returns a new strong reference, to be given back with
+        #[doc = concat!("`", stringify!([<$prefix _release>]), "()`.")]
+        #[$crate::ffi_export]
+        fn [<$prefix _retain>] (
+            arc: &'_ ThinArc,
+        ) -> ThinArc
+        {
+            arc.clone()
+        }
+
+        /// Gives back a strong reference, dropping the value if it was the
+        /// last one.
+        #[$crate::ffi_export]
+        fn [<$prefix _release>] (
+            arc: ThinArc,
+        )
+        {
+            $crate::ඞ::drop(arc)
+        }
+
+        /// Returns a new weak reference, to be given back with
+        #[doc = concat!("`", stringify!([<$prefix _weak_release>]), "()`.")]
+        #[$crate::ffi_export]
+        fn [<$prefix _downgrade>] (
+            arc: &'_ ThinArc,
+        ) -> ThinWeak
+        {
+            ThinArc::downgrade(arc)
+        }
+
+        /// Returns a new weak reference, to be given back with
+        #[doc = concat!("`", stringify!([<$prefix _weak_release>]), "()`.")]
+        #[$crate::ffi_export]
+        fn [<$prefix _weak_retain>] (
+            weak: &'_ ThinWeak,
+        ) -> ThinWeak
+        {
+            weak.clone()
+        }
+
+        /// Gives back a weak reference.
+        #[$crate::ffi_export]
+        fn [<$prefix _weak_release>] (
+            weak: ThinWeak,
+        )
+        {
+            $crate::ඞ::drop(weak)
+        }
+
+        /// Returns a new strong reference, to be given back with
+        #[doc = concat!("`", stringify!([<$prefix _release>]), "()`,")]
+        /// or `NULL` if the value has already been dropped.
+        #[$crate::ffi_export]
+        fn [<$prefix _weak_upgrade>] (
+            weak: &'_ ThinWeak,
+        ) -> $crate::ඞ::Option<ThinArc>
+        {
+            weak.upgrade()
+        }
+    };
+})}
+
 /// A `?Sized`-aware alias, for convenience:
 ///
 ///   - when `T : Sized`, this is [`ThinArc<T>`];
+///   - when `T = [U]`, this is [`c_slice::Arc<U>`];
+///   - when `T = str`, this is [`str::Arc`][crate::string::str_arc];
 ///   - when `T = dyn 'static + Send + Sync + Fn(…) -> _`, this is [the dedicated hand-rolled
 ///     FFI-safe `dyn` "closure" struct of the given arity][crate::closure::arc].
 ///
@@ -209,3 +433,50 @@ pub trait FitForCArc {
 impl<T: Sized> FitForCArc for T {
     type CArcWrapped = ThinArc<T>;
 }
+
+impl<T: Sized> FitForCArc for [T] {
+    type CArcWrapped = c_slice::Arc<T>;
+}
+
+impl FitForCArc for str {
+    type CArcWrapped = crate::string::str_arc;
+}
+
+#[test]
+fn weak() {
+    let arc = ThinArc::new(42);
+    let weak = ThinArc::downgrade(&arc);
+    assert_eq!(weak.strong_count(), 1);
+    assert_eq!(weak.clone().upgrade().as_deref(), Some(&42));
+    drop(arc);
+    assert!(weak.upgrade().is_none());
+
+    // A dangling `Weak`, sent through C (by value) and back.
+    let dangling = ThinWeak::from(rust::Weak::<u64>::new());
+    let c_weak = unsafe { crate::layout::into_raw(dangling.clone()) };
+    let dangling = unsafe { crate::layout::from_raw::<ThinWeak<u64>>(c_weak) }.unwrap();
+    assert!(dangling.upgrade().is_none());
+    assert_eq!(dangling.strong_count(), 0);
+    assert!(rust::Weak::ptr_eq(&dangling.into(), &rust::Weak::new()));
+}
+
+#[test]
+fn slices() {
+    let arced: rust::Arc<[u8]> = b"hello"[..].into();
+    let c_arced = Arc::<[u8]>::from(arced.clone());
+    assert_eq!(rust::Arc::strong_count(&arced), 2);
+    let c_clone = c_arced.clone();
+    assert_eq!(rust::Arc::strong_count(&arced), 3);
+    assert_eq!(&*c_clone, b"hello");
+
+    let bytes = crate::bytes::Bytes::from(c_clone);
+    assert_eq!(bytes, b"hello");
+    let c_arced_back = c_slice::Arc::<u8>::try_from(bytes).unwrap();
+    assert!(::core::ptr::eq(c_arced_back.as_ptr(), arced.as_ptr()));
+    drop((c_arced, c_arced_back));
+    assert_eq!(rust::Arc::strong_count(&arced), 1);
+
+    let s = Arc::<str>::from("hi");
+    assert_eq!(&*s.clone(), "hi");
+    assert_eq!(&*rust::Arc::<str>::from(s), "hi");
+}
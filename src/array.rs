@@ -0,0 +1,403 @@
+//! Inline, fixed-capacity strings and arrays.
+//!
+//! These are meant for (short) struct fields, for which a [`char_p::Box`] or
+//! a [`repr_c::Vec`] would require a heap allocation, and thus a function to
+//! free it, on the C side.
+//!
+//! Both are stored as a `uint8_t` length followed by the (inline) buffer, so
+//! their capacity `N` cannot exceed `255`.
+//!
+//! [`char_p::Box`]: crate::prelude::char_p::Box
+//! [`repr_c::Vec`]: crate::prelude::repr_c::Vec
+
+use_prelude!();
+
+/// Error for contents not fitting within the capacity of an [`ArrayVec`] or
+/// [`ArrayString`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError {
+    pub capacity: usize,
+}
+
+impl fmt::Display for CapacityError {
+    fn fmt(
+        self: &'_ Self,
+        fmt: &'_ mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(fmt, "contents exceed the inline capacity of {}", self.capacity)
+    }
+}
+
+cfg_std! {
+    impl ::std::error::Error for CapacityError {}
+}
+
+/// The C layouts of [`ArrayVec`] and [`ArrayString`], which hand-roll their
+/// `ReprC` impls so as to check `len` against the (whole) `buf`.
+mod c_layout {
+    use super::*;
+
+    #[derive_ReprC]
+    #[repr(C)]
+    pub struct ArrayVec<T, const N: usize> {
+        pub len: u8,
+        pub buf: [T; N],
+    }
+
+    #[derive_ReprC]
+    #[repr(C)]
+    pub struct ArrayString<const N: usize> {
+        pub len: u8,
+        pub buf: [c_char; N],
+    }
+}
+
+/// Post-monomorphization error for an `N` which does not fit in the `uint8_t`
+/// length, triggered from the constructors, the mutators, and `is_valid()`
+/// (since an instance may come from C, or be zero-initialized).
+struct CheckCapacity<const N: usize>;
+
+impl<const N: usize> CheckCapacity<N> {
+    const OK: () = assert!(N <= u8::MAX as usize, "the capacity must not exceed 255");
+}
+
+/// An array of (up to `N`) `T`s, stored inline.
+///
+/// ```C
+/// typedef struct ArrayVec_int32_4 {
+///     uint8_t len;
+///     int32_t buf[4];
+/// } ArrayVec_int32_4_t;
+/// ```
+///
+/// Only the first `len` elements are meaningful, but the whole `buf` is to
+/// be initialized (_e.g._, zeroed), since the C side may read it.
+///
+/// When received from C, `len` is checked not to exceed `N`.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ArrayVec<T, const N: usize> {
+    len: u8,
+    buf: [T; N],
+}
+
+unsafe impl<T: ReprC, const N: usize> ReprC for ArrayVec<T, N> {
+    type CLayout = CLayoutOf<c_layout::ArrayVec<T, N>>;
+
+    #[inline]
+    fn is_valid(it: &'_ Self::CLayout) -> bool {
+        let () = CheckCapacity::<N>::OK;
+        usize::from(it.len) <= N && <c_layout::ArrayVec<T, N> as ReprC>::is_valid(it)
+    }
+
+    #[inline]
+    fn __canonicalize__(it: &'_ mut Self::CLayout) {
+        <c_layout::ArrayVec<T, N> as ReprC>::__canonicalize__(it)
+    }
+}
+
+impl<T: Default, const N: usize> ArrayVec<T, N> {
+    /// An empty array, with the whole `buf` set to `T::default()`.
+    #[inline]
+    pub fn new() -> ArrayVec<T, N> {
+        let () = CheckCapacity::<N>::OK;
+        Self {
+            len: 0,
+            buf: ::core::array::from_fn(|_| T::default()),
+        }
+    }
+}
+
+impl<T, const N: usize> ArrayVec<T, N> {
+    pub const CAPACITY: usize = N;
+
+    #[inline]
+    pub fn len(self: &'_ ArrayVec<T, N>) -> usize {
+        self.len.into()
+    }
+
+    #[inline]
+    pub fn is_empty(self: &'_ ArrayVec<T, N>) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    pub fn as_slice(self: &'_ ArrayVec<T, N>) -> &'_ [T] {
+        &self.buf[.. self.len()]
+    }
+
+    #[inline]
+    pub fn as_mut_slice(self: &'_ mut ArrayVec<T, N>) -> &'_ mut [T] {
+        let len = self.len();
+        &mut self.buf[.. len]
+    }
+
+    /// Fails, giving `value` back, if the array is full.
+    pub fn push(
+        self: &'_ mut ArrayVec<T, N>,
+        value: T,
+    ) -> Result<(), T> {
+        let () = CheckCapacity::<N>::OK;
+        match self.buf.get_mut(self.len()) {
+            | Some(slot) => {
+                *slot = value;
+                self.len += 1;
+                Ok(())
+            },
+            | None => Err(value),
+        }
+    }
+
+    /// Only resets the length: the elements are left in the `buf`.
+    #[inline]
+    pub fn clear(self: &'_ mut ArrayVec<T, N>) {
+        self.len = 0;
+    }
+}
+
+impl<T: Default, const N: usize> Default for ArrayVec<T, N> {
+    #[inline]
+    fn default() -> ArrayVec<T, N> {
+        Self::new()
+    }
+}
+
+impl<'lt, T: Clone + Default, const N: usize> TryFrom<&'lt [T]> for ArrayVec<T, N> {
+    type Error = CapacityError;
+
+    fn try_from(slice: &'lt [T]) -> Result<ArrayVec<T, N>, CapacityError> {
+        let mut ret = Self::new();
+        if slice.len() > N {
+            return Err(CapacityError { capacity: N });
+        }
+        ret.buf[.. slice.len()].clone_from_slice(slice);
+        ret.len = slice.len() as u8;
+        Ok(ret)
+    }
+}
+
+impl<T, const N: usize> Deref for ArrayVec<T, N> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(self: &'_ ArrayVec<T, N>) -> &'_ [T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> DerefMut for ArrayVec<T, N> {
+    #[inline]
+    fn deref_mut(self: &'_ mut ArrayVec<T, N>) -> &'_ mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for ArrayVec<T, N> {
+    #[inline]
+    fn eq(
+        self: &'_ Self,
+        other: &'_ Self,
+    ) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for ArrayVec<T, N> {}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for ArrayVec<T, N> {
+    fn fmt(
+        self: &'_ Self,
+        fmt: &'_ mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        <[T] as fmt::Debug>::fmt(self, fmt)
+    }
+}
+
+#[inline]
+fn as_bytes(buf: &'_ [c_char]) -> &'_ [u8] {
+    unsafe {
+        // Safety: `c_char` is a `#[repr(transparent)]` wrapper around `u8`.
+        &*(buf as *const [c_char] as *const [u8])
+    }
+}
+
+/// A UTF-8 string of (up to `N`) bytes, stored inline.
+///
+/// ```C
+/// typedef struct ArrayString_16 {
+///     uint8_t len;
+///     char buf[16];
+/// } ArrayString_16_t;
+/// ```
+///
+/// Note that `buf` is **not** `NUL`-terminated.
+///
+/// When received from C, `len` is checked not to exceed `N`, and the first
+/// `len` bytes of `buf` to be valid UTF-8.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ArrayString<const N: usize> {
+    len: u8,
+    buf: [c_char; N],
+}
+
+unsafe impl<const N: usize> ReprC for ArrayString<N> {
+    type CLayout = CLayoutOf<c_layout::ArrayString<N>>;
+
+    #[inline]
+    fn is_valid(it: &'_ Self::CLayout) -> bool {
+        let () = CheckCapacity::<N>::OK;
+        let len = usize::from(it.len);
+        len <= N && ::core::str::from_utf8(as_bytes(&it.buf[.. len])).is_ok()
+    }
+}
+
+impl<const N: usize> ArrayString<N> {
+    pub const CAPACITY: usize = N;
+
+    /// An empty string, with a zeroed `buf`.
+    #[inline]
+    pub const fn new() -> ArrayString<N> {
+        let () = CheckCapacity::<N>::OK;
+        Self {
+            len: 0,
+            buf: [c_char(b'\0'); N],
+        }
+    }
+
+    #[inline]
+    pub fn len(self: &'_ ArrayString<N>) -> usize {
+        self.len.into()
+    }
+
+    #[inline]
+    pub fn is_empty(self: &'_ ArrayString<N>) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    pub fn as_str(self: &'_ ArrayString<N>) -> &'_ str {
+        unsafe {
+            // Safety: the first `len` bytes are UTF-8 by construction.
+            ::core::str::from_utf8_unchecked(as_bytes(&self.buf[.. self.len()]))
+        }
+    }
+
+    /// Fails, leaving `self` untouched, if `s` does not fit.
+    pub fn push_str(
+        self: &'_ mut ArrayString<N>,
+        s: &'_ str,
+    ) -> Result<(), CapacityError> {
+        let () = CheckCapacity::<N>::OK;
+        let len = self.len();
+        let dst = self
+            .buf
+            .get_mut(len .. len + s.len())
+            .ok_or(CapacityError { capacity: N })?;
+        for (dst, &byte) in dst.iter_mut().zip(s.as_bytes()) {
+            *dst = c_char(byte);
+        }
+        self.len += s.len() as u8;
+        Ok(())
+    }
+
+    /// Only resets the length: the bytes are left in the `buf`.
+    #[inline]
+    pub fn clear(self: &'_ mut ArrayString<N>) {
+        self.len = 0;
+    }
+}
+
+impl<const N: usize> Default for ArrayString<N> {
+    #[inline]
+    fn default() -> ArrayString<N> {
+        Self::new()
+    }
+}
+
+impl<'lt, const N: usize> TryFrom<&'lt str> for ArrayString<N> {
+    type Error = CapacityError;
+
+    #[inline]
+    fn try_from(s: &'lt str) -> Result<ArrayString<N>, CapacityError> {
+        let mut ret = Self::new();
+        ret.push_str(s)?;
+        Ok(ret)
+    }
+}
+
+impl<const N: usize> Deref for ArrayString<N> {
+    type Target = str;
+
+    #[inline]
+    fn deref(self: &'_ ArrayString<N>) -> &'_ str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for ArrayString<N> {
+    #[inline]
+    fn as_ref(self: &'_ Self) -> &'_ str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> PartialEq for ArrayString<N> {
+    #[inline]
+    fn eq(
+        self: &'_ Self,
+        other: &'_ Self,
+    ) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> Eq for ArrayString<N> {}
+
+impl<const N: usize> fmt::Debug for ArrayString<N> {
+    fn fmt(
+        self: &'_ Self,
+        fmt: &'_ mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        <str as fmt::Debug>::fmt(self, fmt)
+    }
+}
+
+impl<const N: usize> fmt::Display for ArrayString<N> {
+    fn fmt(
+        self: &'_ Self,
+        fmt: &'_ mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        <str as fmt::Display>::fmt(self, fmt)
+    }
+}
+
+#[test]
+fn inline_arrays() {
+    use crate::layout::from_raw;
+    use crate::layout::into_raw;
+
+    let mut name = ArrayString::<8>::try_from("safer").unwrap();
+    assert!(name.push_str("_ffi").is_err());
+    assert_eq!(name.as_str(), "safer");
+    name.push_str("ffi").unwrap();
+    assert_eq!(name.as_str(), "saferffi");
+    assert!(ArrayString::<4>::try_from("too long").is_err());
+
+    let mut raw = unsafe { into_raw(name) };
+    assert!(unsafe { from_raw::<ArrayString<8>>(raw) }.is_some());
+    raw.buf[0] = c_char(0xff);
+    assert!(unsafe { from_raw::<ArrayString<8>>(raw) }.is_none());
+    raw.buf[0] = c_char(b's');
+    raw.len = 9;
+    assert!(unsafe { from_raw::<ArrayString<8>>(raw) }.is_none());
+
+    let mut xs = ArrayVec::<u32, 2>::new();
+    xs.push(27).unwrap();
+    xs.push(42).unwrap();
+    assert_eq!(xs.push(0), Err(0));
+    assert_eq!(*xs, [27, 42]);
+    let mut raw = unsafe { into_raw(xs) };
+    raw.len = 3;
+    assert!(unsafe { from_raw::<ArrayVec<u32, 2>>(raw) }.is_none());
+}
@@ -134,6 +134,23 @@ unsafe impl<T> Send for ThinBox<T> where rust::Box<T>: Send {}
 
 unsafe impl<T> Sync for ThinBox<T> where rust::Box<T>: Sync {}
 
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for ThinBox<T> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        T::serialize(&**self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for ThinBox<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(ThinBox::new)
+    }
+}
+
 impl<T: Clone> Clone for ThinBox<T> {
     #[inline]
     fn clone(self: &'_ Self) -> Self {
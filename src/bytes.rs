@@ -5,6 +5,8 @@ use core::fmt::Debug;
 use core::hash::Hash;
 use core::mem;
 use core::ops::Deref;
+#[cfg(feature = "alloc")]
+use core::ops::DerefMut;
 use core::ops::RangeBounds;
 use core::ptr;
 
@@ -141,6 +143,47 @@ impl<'a> Bytes<'a> {
         }
     }
 
+    /// Constructs a [`Bytes`] from a buffer owned by foreign code, never attempting to inline the
+    /// data.
+    ///
+    /// `release(owner)` is called once the last [`Bytes`] referring to the buffer is dropped.
+    /// Cloning such a [`Bytes`] will copy the data.
+    ///
+    /// If `release` is null, the resulting [`Bytes`] merely borrows `slice` (as with
+    /// [`Bytes::from_slice`]), and [upgrading](Bytes::upgrade) it will copy the data.
+    ///
+    /// # SAFETY
+    /// - If `release` is non-null, `slice` MUST stay valid until `release(owner)` is called, and
+    ///   calling it once (from any thread) with `owner` as argument MUST be safe.
+    /// - If `release` is null, `slice` MUST stay valid for `'a`.
+    pub unsafe fn from_foreign(
+        slice: &'a [u8],
+        owner: *const (),
+        release: Option<unsafe extern "C" fn(*const ())>,
+    ) -> Self {
+        unsafe extern "C" fn release_foreign(
+            owner: *const (),
+            release: usize,
+        ) {
+            unsafe {
+                let release = mem::transmute::<usize, unsafe extern "C" fn(*const ())>(release);
+                release(owner)
+            }
+        }
+        static FOREIGN_VT: BytesVt = BytesVt {
+            retain: None,
+            release: Some(release_foreign),
+        };
+        match release {
+            | Some(release) => unsafe {
+                // `capacity` is free to be used as a second part of `owner`.
+                Self::from_raw_parts(slice, owner, release as usize, &FOREIGN_VT)
+            },
+            // Merely borrowed: no `release`, so that upgrading it copies the data.
+            | None => Self::from_slice_no_inline(slice, owner),
+        }
+    }
+
     /// Constructs a [`Bytes`] referring to static data.
     ///
     /// This is equivalent to `<Bytes as From<&'static [u8]>>::from`, guaranteeing that
@@ -181,16 +224,22 @@ impl<'a> Bytes<'a> {
     /// for free upgrades. You may use the [`Bytes::inline_slice`] constructor instead of this one
     /// if you want to be able to handle inlining not being possible.
     pub const fn from_slice(data: &'a [u8]) -> Self {
-        const VT: BytesVt = BytesVt {
-            release: None,
-            retain: Some(noop),
-        };
         if data.len() <= Self::MAX_INLINE_SIZE {
             unsafe { Self::inline_unchecked(data) }
         } else {
-            unsafe { Self::from_raw_parts(data, data.as_ptr().cast(), data.len(), &VT) }
+            Self::from_slice_no_inline(data, data.as_ptr().cast())
         }
     }
+    const fn from_slice_no_inline(
+        data: &'a [u8],
+        owner: *const (),
+    ) -> Self {
+        const VT: BytesVt = BytesVt {
+            release: None,
+            retain: Some(noop),
+        };
+        unsafe { Self::from_raw_parts(data, owner, data.len(), &VT) }
+    }
 
     /// Constructs a [`Bytes`] from a short slice by inlining it, untying [`Bytes`]'s lifetime from
     /// `slice`'s.
@@ -391,7 +440,7 @@ impl<'a> Bytes<'a> {
     /// # Errors
     /// Returns the original instance if it couldn't be proven to be `'static`.
     pub fn noalloc_upgrade(self: Bytes<'a>) -> Result<Bytes<'static>, Self> {
-        if !self.vtable().map_or(true, |vt| !vt.is_borrowed()) {
+        if self.vtable().map_or(true, |vt| !vt.is_borrowed()) {
             Ok(unsafe { mem::transmute(self) })
         } else {
             Self::inline_slice(&self).ok_or(self)
@@ -832,6 +881,31 @@ impl<'a> TryFrom<Bytes<'a>> for Arc<[u8]> {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl From<crate::slice::slice_arc<u8>> for Bytes<'static> {
+    fn from(data: crate::slice::slice_arc<u8>) -> Self {
+        Arc::<[u8]>::from(data).into()
+    }
+}
+#[cfg(feature = "alloc")]
+impl From<crate::string::str_arc> for Bytes<'static> {
+    fn from(data: crate::string::str_arc) -> Self {
+        data.into_bytes().into()
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Attempts to downcast the [`Bytes`] into its inner `Arc<[u8]>`, as a
+/// [`c_slice::Arc<u8>`](crate::slice::slice_arc).
+///
+/// See the `Arc<[u8]>` conversion for the requirements.
+impl<'a> TryFrom<Bytes<'a>> for crate::slice::slice_arc<u8> {
+    type Error = Bytes<'a>;
+    fn try_from(value: Bytes<'a>) -> Result<Self, Self::Error> {
+        Arc::<[u8]>::try_from(value).map(Into::into)
+    }
+}
+
 #[cfg(feature = "stabby")]
 /// Attempts to downcast the [`Bytes`] into its inner
 /// [`stabby::sync::ArcSlice<u8>`](stabby::sync::ArcSlice).
@@ -864,6 +938,424 @@ impl<'a> TryFrom<Bytes<'a>> for stabby::sync::ArcSlice<u8> {
     }
 }
 
+#[cfg(feature = "bytes")]
+/// Shares `value`'s buffer (by wrapping it in an `Arc`) rather than copying it, unless it is
+/// short enough to be [inlined](Bytes::inline_slice).
+impl From<::bytes::Bytes> for Bytes<'static> {
+    fn from(value: ::bytes::Bytes) -> Self {
+        Self::inline_slice(&value).unwrap_or_else(|| Arc::new(value).into())
+    }
+}
+#[cfg(feature = "bytes")]
+impl From<::bytes::BytesMut> for Bytes<'static> {
+    fn from(value: ::bytes::BytesMut) -> Self {
+        value.freeze().into()
+    }
+}
+#[cfg(feature = "bytes")]
+/// Hands `value` over to a [`bytes::Bytes`](::bytes::Bytes) as its owner, without copying it.
+///
+/// # Errors
+/// Returns the original instance if it couldn't be proven to be `'static` without allocating (see
+/// [`Bytes::noalloc_upgrade`]); [`Bytes::upgrade`] it first if copying is acceptable.
+impl<'a> TryFrom<Bytes<'a>> for ::bytes::Bytes {
+    type Error = Bytes<'a>;
+    fn try_from(value: Bytes<'a>) -> Result<Self, Self::Error> {
+        value.noalloc_upgrade().map(::bytes::Bytes::from_owner)
+    }
+}
+#[cfg(feature = "bytes")]
+impl ::bytes::Buf for Bytes<'_> {
+    fn remaining(&self) -> usize {
+        self.len()
+    }
+    fn chunk(&self) -> &[u8] {
+        self.as_slice()
+    }
+    fn advance(
+        &mut self,
+        cnt: usize,
+    ) {
+        self.shrink_to(cnt..)
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl Bytes<'static> {
+    /// Maps `file` (read-only) into memory, without copying its contents.
+    ///
+    /// The mapping is kept alive until the last [`Bytes`] referring to it (_e.g._, a
+    /// [subslice](Bytes::subsliced)) is dropped; cloning does not copy the data either.
+    ///
+    /// # SAFETY
+    /// The file MUST NOT be modified (or truncated), by this or another process, for as long as
+    /// the mapping is alive, since that would mutate (or unmap) memory behind a `&[u8]`.
+    pub unsafe fn from_mmap(file: std::fs::File) -> std::io::Result<Self> {
+        let mmap = unsafe { ::memmap2::Mmap::map(&file)? };
+        Ok(Arc::new(mmap).into())
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// A growable, uniquely owned buffer, meant to be filled (from Rust or C) and then
+/// [frozen](BytesMut::freeze) into a shareable [`Bytes`].
+///
+/// It has the same layout as a [`repr_c::Vec<u8>`](crate::vec::Vec).
+///
+/// See [`ffi_export_bytes_mut_helpers!`](crate::ffi_export_bytes_mut_helpers) for its C API.
+#[derive_ReprC]
+#[repr(transparent)]
+#[derive(Clone)]
+pub struct BytesMut(crate::vec::Vec<u8>);
+
+#[cfg(feature = "alloc")]
+impl BytesMut {
+    /// Constructs an empty buffer, without allocating.
+    pub const fn new() -> Self {
+        Self(crate::vec::Vec::EMPTY)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        alloc::vec::Vec::with_capacity(capacity).into()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+
+    /// Ensures at least `additional` more bytes can be written without reallocating.
+    pub fn reserve(
+        &mut self,
+        additional: usize,
+    ) {
+        self.0.with_rust_mut(|vec| vec.reserve(additional))
+    }
+
+    pub fn extend_from_slice(
+        &mut self,
+        data: &[u8],
+    ) {
+        self.0.with_rust_mut(|vec| vec.extend_from_slice(data))
+    }
+
+    pub fn clear(&mut self) {
+        self.0.with_rust_mut(|vec| vec.clear())
+    }
+
+    /// Splits the buffer in two, returning `[0, at)` and leaving `[at, len)` in `self`.
+    ///
+    /// Unlike [`bytes::BytesMut::split_to`](https://docs.rs/bytes/1/bytes/struct.BytesMut.html#method.split_to),
+    /// the buffer is uniquely owned, so the `[0, at)` head gets copied into a new allocation,
+    /// with the tail being shifted to the start of `self`'s (kept) allocation.
+    /// ```
+    /// # use safer_ffi::bytes::BytesMut;
+    /// let mut buf = BytesMut::from(b"Hello there".to_vec());
+    /// let hello = buf.split_to(5);
+    /// assert_eq!((hello.as_slice(), buf.as_slice()), (&b"Hello"[..], &b" there"[..]));
+    /// ```
+    /// # Panics
+    /// If `at > self.len()`.
+    pub fn split_to(
+        &mut self,
+        at: usize,
+    ) -> Self {
+        self.0.with_rust_mut(|vec| vec.drain(..at).as_slice().to_vec()).into()
+    }
+
+    /// The (uninitialized) memory past `len()`, up to `capacity()`, to be written to before
+    /// calling [`Self::set_len()`].
+    pub fn spare_capacity_mut(&mut self) -> &mut [mem::MaybeUninit<u8>] {
+        self.0.spare_capacity_mut()
+    }
+
+    /// # Safety
+    ///
+    /// `new_len <= capacity()`, and the bytes up to `new_len` must have been written to.
+    pub unsafe fn set_len(
+        &mut self,
+        new_len: usize,
+    ) {
+        unsafe { self.0.set_len(new_len) }
+    }
+
+    /// Converts the buffer into a [`Bytes`], without copying it (unless it is short enough to be
+    /// [inlined](Bytes::inline_slice)).
+    ///
+    /// The resulting [`Bytes`] can be cloned without reallocating.
+    pub fn freeze(self) -> Bytes<'static> {
+        let vec = alloc::vec::Vec::<u8>::from(self);
+        Bytes::inline_slice(&vec).unwrap_or_else(|| Arc::new(vec).into())
+    }
+}
+#[cfg(feature = "alloc")]
+impl Default for BytesMut {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+#[cfg(feature = "alloc")]
+impl From<alloc::vec::Vec<u8>> for BytesMut {
+    fn from(value: alloc::vec::Vec<u8>) -> Self {
+        Self(value.into())
+    }
+}
+#[cfg(feature = "alloc")]
+impl From<BytesMut> for alloc::vec::Vec<u8> {
+    fn from(value: BytesMut) -> Self {
+        value.0.into()
+    }
+}
+#[cfg(feature = "alloc")]
+impl From<BytesMut> for Bytes<'static> {
+    fn from(value: BytesMut) -> Self {
+        value.freeze()
+    }
+}
+#[cfg(feature = "alloc")]
+impl Deref for BytesMut {
+    type Target = [u8];
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+#[cfg(feature = "alloc")]
+impl DerefMut for BytesMut {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+#[cfg(feature = "alloc")]
+impl AsRef<[u8]> for BytesMut {
+    fn as_ref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+#[cfg(feature = "alloc")]
+impl Extend<u8> for BytesMut {
+    fn extend<I: IntoIterator<Item = u8>>(
+        &mut self,
+        iter: I,
+    ) {
+        self.0.with_rust_mut(|vec| vec.extend(iter))
+    }
+}
+#[cfg(feature = "alloc")]
+impl Debug for BytesMut {
+    fn fmt(
+        &self,
+        f: &mut core::fmt::Formatter<'_>,
+    ) -> core::fmt::Result {
+        Debug::fmt(self.as_slice(), f)
+    }
+}
+#[cfg(feature = "std")]
+impl std::io::Write for BytesMut {
+    fn write(
+        &mut self,
+        buf: &[u8],
+    ) -> std::io::Result<usize> {
+        self.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Exports the C API of [`BytesMut`], so that foreign code may fill a buffer which Rust will
+/// then share as a [`Bytes`]:
+///
+/// ```c
+/// BytesMut_t bytes_mut_with_capacity(size_t capacity);
+/// void bytes_mut_reserve(BytesMut_t * buf, size_t additional);
+/// void bytes_mut_extend_from_slice(BytesMut_t * buf, slice_ref_uint8_t data);
+/// /* Writable memory past `len` (`buf->cap - buf->len` bytes), committed with `set_len`. */
+/// uint8_t * bytes_mut_spare_capacity(BytesMut_t * buf);
+/// void bytes_mut_set_len(BytesMut_t * buf, size_t new_len);
+/// Bytes_t bytes_mut_freeze(BytesMut_t buf);
+/// void bytes_mut_drop(BytesMut_t buf);
+/// ```
+///
+/// To be invoked once, _e.g._, at the root of the crate.
+#[cfg(feature = "alloc")]
+#[macro_export]
+#[cfg_attr(rustfmt, rustfmt::skip)]
+macro_rules! ffi_export_bytes_mut_helpers {() => (
+    const _: () = {
+        use $crate::bytes::{Bytes, BytesMut};
+        use $crate::prelude::c_slice;
+
+        #[$crate::ffi_export]
+        fn bytes_mut_with_capacity (
+            capacity: usize,
+        ) -> BytesMut
+        {
+            BytesMut::with_capacity(capacity)
+        }
+
+        #[$crate::ffi_export]
+        fn bytes_mut_reserve (
+            buf: &'_ mut BytesMut,
+            additional: usize,
+        )
+        {
+            buf.reserve(additional)
+        }
+
+        #[$crate::ffi_export]
+        fn bytes_mut_extend_from_slice (
+            buf: &'_ mut BytesMut,
+            data: c_slice::Ref<'_, u8>,
+        )
+        {
+            buf.extend_from_slice(data.as_slice())
+        }
+
+        /// Returns a pointer to the `capacity - len` bytes past the end of the buffer, which
+        /// may be written to (_e.g._, after a `bytes_mut_reserve()`), and then committed with
+        /// `bytes_mut_set_len()`.
+        #[$crate::ffi_export]
+        fn bytes_mut_spare_capacity (
+            buf: &'_ mut BytesMut,
+        ) -> *mut u8
+        {
+            buf.spare_capacity_mut().as_mut_ptr().cast()
+        }
+
+        /// `new_len` MUST be `<= capacity`, with the bytes up to it having been written to.
+        #[$crate::ffi_export]
+        unsafe fn bytes_mut_set_len (
+            buf: &'_ mut BytesMut,
+            new_len: usize,
+        )
+        {
+            unsafe { buf.set_len(new_len) }
+        }
+
+        #[$crate::ffi_export]
+        fn bytes_mut_freeze (
+            buf: BytesMut,
+        ) -> Bytes<'static>
+        {
+            buf.freeze()
+        }
+
+        #[$crate::ffi_export]
+        fn bytes_mut_drop (
+            buf: BytesMut,
+        )
+        {
+            $crate::ඞ::drop(buf)
+        }
+    };
+)}
+
+/// Exports a C API to handle [`Bytes`] (the [`BytesVt`] functions being only usable from Rust):
+///
+/// ```c
+/// Bytes_t bytes_clone(Bytes_t const * bytes);
+/// void bytes_drop(Bytes_t bytes);
+/// /* Returns `NULL` (`.vtable == NULL`) if `start > end` or `end > bytes->len`. */
+/// Bytes_t bytes_subslice(Bytes_t const * bytes, size_t start, size_t end);
+/// Bytes_t bytes_from_copy(slice_ref_uint8_t data);
+/// /* `release(owner)` is called once the buffer is no longer used (if non-`NULL`).
+///  * If `release` is `NULL`, the buffer MUST remain valid (and unmodified) forever.
+///  * `ptr` may only be `NULL` if `len == 0`; otherwise, `NULL` (`.vtable == NULL`) is returned. */
+/// Bytes_t bytes_from_raw_parts(
+///     uint8_t const * ptr, size_t len, void const * owner, void (*release)(void const *));
+/// ```
+///
+/// The read-only contents of a `Bytes_t` are to be obtained from Rust, _e.g._, with a function
+/// returning a `c_slice::Ref<'_, u8>` out of a `&'_ Bytes<'_>`.
+///
+/// To be invoked once, _e.g._, at the root of the crate.
+#[cfg(feature = "alloc")]
+#[macro_export]
+#[cfg_attr(rustfmt, rustfmt::skip)]
+macro_rules! ffi_export_bytes_helpers {() => (
+    const _: () = {
+        use $crate::bytes::Bytes;
+        use $crate::prelude::c_slice;
+
+        #[$crate::ffi_export]
+        fn bytes_clone (
+            bytes: &'_ Bytes<'static>,
+        ) -> Bytes<'static>
+        {
+            bytes.clone()
+        }
+
+        #[$crate::ffi_export]
+        fn bytes_drop (
+            bytes: Bytes<'static>,
+        )
+        {
+            $crate::ඞ::drop(bytes)
+        }
+
+        #[$crate::ffi_export]
+        fn bytes_subslice (
+            bytes: &'_ Bytes<'static>,
+            start: usize,
+            end: usize,
+        ) -> $crate::ඞ::Option<Bytes<'static>>
+        {
+            if start > end || end > bytes.len() {
+                return $crate::ඞ::None;
+            }
+            $crate::ඞ::Some(match bytes.noalloc_clone() {
+                | $crate::ඞ::Some(bytes) => bytes.subsliced(start..end),
+                | $crate::ඞ::None => Bytes::copied_from_slice(&bytes[start..end]),
+            })
+        }
+
+        #[$crate::ffi_export]
+        fn bytes_from_copy (
+            data: c_slice::Ref<'_, u8>,
+        ) -> Bytes<'static>
+        {
+            Bytes::copied_from_slice(data.as_slice())
+        }
+
+        /// `release(owner)` is called once the buffer is no longer used (if non-`NULL`).
+        ///
+        /// If `release` is `NULL`, the buffer MUST remain valid (and unmodified) forever.
+        ///
+        /// `ptr` may only be `NULL` if `len == 0`; otherwise, `NULL` is returned.
+        #[$crate::ffi_export]
+        unsafe fn bytes_from_raw_parts (
+            ptr: *const u8,
+            len: usize,
+            owner: *const (),
+            release: $crate::ඞ::Option<unsafe extern "C" fn(*const ())>,
+        ) -> $crate::ඞ::Option<Bytes<'static>>
+        {
+            let slice: &'static [u8] = match (ptr.is_null(), len) {
+                | (true, 0) => &[],
+                | (true, _) => return $crate::ඞ::None,
+                | (false, _) => unsafe { $crate::ඞ::core::slice::from_raw_parts(ptr, len) },
+            };
+            $crate::ඞ::Some(unsafe { Bytes::from_foreign(slice, owner, release) })
+        }
+    };
+)}
+
 #[cfg(feature = "alloc")]
 #[test]
 fn fuzz() {
@@ -1029,3 +1521,98 @@ mod tests {
         assert_de_tokens(&Bytes::from(&[0, 1, 2]), &[Token::ByteBuf(&[0, 1, 2])]);
     }
 }
+
+#[cfg(feature = "bytes")]
+#[test]
+fn bytes_crate_interop() {
+    use ::bytes::Buf;
+
+    let data = b"Hello there, this is long enough not to be inlined on any platform";
+    let theirs = ::bytes::Bytes::from_static(data);
+    let mut ours = Bytes::from(theirs.clone());
+    assert_eq!(ours.as_ptr(), theirs.as_ptr());
+    assert_eq!(ours.get_u8(), b'H');
+    assert_eq!(ours.remaining(), data.len() - 1);
+    let back = ::bytes::Bytes::try_from(ours.clone()).unwrap();
+    assert_eq!(back.as_ptr(), ours.as_ptr());
+    assert_eq!(back, &data[1..]);
+
+    let borrowed = Bytes::from_slice(data.as_slice());
+    assert!(::bytes::Bytes::try_from(borrowed).is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn bytes_mut() {
+    use std::io::Write;
+
+    let mut buf = BytesMut::with_capacity(4);
+    write!(buf, "Hello there, {}!", "this is long enough not to be inlined").unwrap();
+    buf.extend_from_slice(b" Bye.");
+    let hello = buf.split_to(5);
+    assert_eq!(hello.as_slice(), b"Hello");
+    let ptr = buf.as_ptr();
+    let frozen = buf.freeze();
+    assert_eq!(frozen.as_ptr(), ptr);
+    assert!(!frozen.clone_will_allocate());
+    assert_eq!(
+        frozen,
+        b" there, this is long enough not to be inlined! Bye."
+    );
+    assert_eq!(hello.freeze(), b"Hello");
+
+    let mut buf = BytesMut::with_capacity(3);
+    let spare = buf.spare_capacity_mut();
+    assert!(spare.len() >= 3);
+    spare[..3].copy_from_slice(&[mem::MaybeUninit::new(b'C'); 3]);
+    unsafe { buf.set_len(3) };
+    assert_eq!(buf.as_slice(), b"CCC");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn foreign() {
+    use core::sync::atomic::AtomicUsize;
+    use core::sync::atomic::Ordering;
+
+    static RELEASED: AtomicUsize = AtomicUsize::new(0);
+    unsafe extern "C" fn release(owner: *const ()) {
+        unsafe {
+            drop(alloc::boxed::Box::from_raw(owner.cast::<[u8; 64]>().cast_mut()));
+        }
+        RELEASED.fetch_add(1, Ordering::SeqCst);
+    }
+
+    let owner = alloc::boxed::Box::into_raw(alloc::boxed::Box::new([42_u8; 64]));
+    let bytes = unsafe {
+        Bytes::from_foreign(&(*owner)[..], owner.cast(), Some(release))
+    };
+    let copy = bytes.clone();
+    assert!(bytes.clone_will_allocate());
+    assert_eq!(bytes.subsliced(1..60), [42_u8; 59]);
+    assert_eq!(RELEASED.load(Ordering::SeqCst), 1);
+    assert_eq!(copy, [42_u8; 64]);
+
+    let data = [42_u8; 64];
+    let borrowed = unsafe { Bytes::from_foreign(&data[..], ptr::null(), None) };
+    assert!(borrowed.upgrade_will_allocate());
+    assert!(borrowed.noalloc_upgrade().is_err());
+}
+
+#[cfg(feature = "mmap")]
+#[test]
+fn mmap() {
+    use std::io::Write;
+
+    let path = std::env::temp_dir().join(format!("safer_ffi-bytes-mmap-{}", std::process::id()));
+    let data = b"Hello there, this is long enough not to be inlined on any platform";
+    std::fs::File::create(&path).unwrap().write_all(data).unwrap();
+    let bytes = unsafe { Bytes::from_mmap(std::fs::File::open(&path).unwrap()) }.unwrap();
+    assert_eq!(bytes, data);
+    assert!(!bytes.clone_will_allocate());
+    let sub = bytes.clone().subsliced(1..60);
+    drop(bytes);
+    assert_eq!(sub, &data[1..60]);
+    drop(sub);
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,619 @@
+//! `#[repr(C)]` [`Box`], [`Vec`] and [`String`] types whose memory is
+//! obtained from (and released to) a _foreign_ [`Allocator`], so that C code
+//! may free them on its own, rather than through a Rust-exported function.
+//!
+//!   - With [`CAlloc`], the memory comes from `malloc()`, and C code is thus
+//!     to `free()` it;
+//!
+//!   - With [`RegisteredAlloc`], the memory comes from the
+//!     [`AllocatorVTable`] registered at runtime by the C side (_c.f._
+//!     [`register_allocator()`], and the C function exported by
+//!     [`ffi_export_register_allocator!`]), and it is thus to be freed
+//!     through its `free` callback.
+//!
+//! Their C layout is the same as that of their [`repr_c`] counterparts, and
+//! the generated headers mention, in the docs of the functions returning
+//! them, the deallocator to use.
+//!
+//! ```rust
+//! use ::safer_ffi::prelude::*;
+//! use ::safer_ffi::c_alloc::{self, CAlloc};
+//!
+//! /// To be `free()`d by the caller.
+//! #[ffi_export]
+//! fn get_greeting() -> c_alloc::String<CAlloc> {
+//!     "Hello, World!".into()
+//! }
+//!
+//! assert_eq!(&*get_greeting(), "Hello, World!");
+//! ```
+//!
+//! Note that, so that any such pointer may be passed to the deallocator,
+//! memory is always allocated, even for zero-sized values (or empty
+//! `Vec`s).
+
+use_prelude!();
+use ::core::alloc::Layout;
+use ::core::sync::atomic::AtomicPtr;
+use ::core::sync::atomic::Ordering;
+
+use ::alloc::alloc::handle_alloc_error;
+
+__cfg_headers__! {
+    use crate::headers::languages::Deallocator;
+}
+
+/// An allocator whose memory C code can release on its own.
+///
+/// # Safety
+///
+/// `alloc()` must return either a null pointer or a pointer to a fresh
+/// allocation fitting the given `layout`, and `dealloc()` must release it,
+/// the same way [`DEALLOCATOR`][Self::DEALLOCATOR] does.
+pub unsafe trait Allocator: 'static {
+    /// How C code is to free the memory of this allocator (mentioned, as
+    /// Markdown, in the headers).
+    const DEALLOCATOR: &'static str;
+
+    fn alloc(layout: Layout) -> *mut u8;
+
+    /// # Safety
+    ///
+    /// `ptr` must have been returned by [`Self::alloc(layout)`][Self::alloc]
+    /// and not have been deallocated yet.
+    unsafe fn dealloc(
+        ptr: *mut u8,
+        layout: Layout,
+    );
+}
+
+/// The (minimal) alignment of `malloc()`-returned pointers on mainstream
+/// platforms.
+#[cfg(not(target_arch = "wasm32"))]
+const MALLOC_ALIGNMENT: usize = 2 * mem::size_of::<usize>();
+
+/// The [`Allocator`] of the C standard library: `malloc()` and `free()`.
+///
+/// # Panics
+///
+/// Allocating a type with an alignment greater than `2 * sizeof(size_t)` (the
+/// guaranteed alignment of `malloc()` on mainstream platforms) panics.
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg_attr(all(docs, feature = "nightly"), doc(cfg(not(target_arch = "wasm32"))))]
+pub enum CAlloc {}
+
+#[cfg(not(target_arch = "wasm32"))]
+unsafe impl Allocator for CAlloc {
+    const DEALLOCATOR: &'static str = "`free()`";
+
+    fn alloc(layout: Layout) -> *mut u8 {
+        assert!(
+            layout.align() <= MALLOC_ALIGNMENT,
+            "`CAlloc` does not support over-aligned types (alignment {})",
+            layout.align(),
+        );
+        unsafe { ::libc::malloc(layout.size().max(1)).cast() }
+    }
+
+    unsafe fn dealloc(
+        ptr: *mut u8,
+        _: Layout,
+    ) {
+        unsafe { ::libc::free(ptr.cast()) }
+    }
+}
+
+/// The allocation functions of the [`RegisteredAlloc`]ator, to be provided
+/// by the C side through [`register_allocator()`].
+#[derive_ReprC]
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct AllocatorVTable {
+    /// Must return a pointer aligned to `align`, to at least `size` bytes,
+    /// or `NULL` on failure.
+    pub alloc: unsafe extern "C" fn(size: usize, align: usize) -> *mut c_void,
+    pub free: unsafe extern "C" fn(ptr: *mut c_void),
+}
+
+static REGISTERED_VTABLE: AtomicPtr<AllocatorVTable> = AtomicPtr::new(ptr::null_mut());
+
+/// Registers the allocator backing [`RegisteredAlloc`].
+///
+/// Only the first registration succeeds: this returns `false`, ignoring the
+/// given `vtable`, if one had already been registered.
+///
+/// This is intended to be called by the C side, through the function
+/// exported by [`ffi_export_register_allocator!`], before any
+/// `RegisteredAlloc` allocation.
+///
+/// # Safety
+///
+/// The `vtable` functions are trusted, for the remainder of the program, to
+/// be callable from any thread, and:
+///
+///   - `alloc(size, align)` must return either `NULL`, or a pointer to a
+///     fresh allocation of at least `size` bytes, aligned to `align`, which
+///     remains valid until given to `free`;
+///
+///   - `free(ptr)` must release any such (non-`NULL`) pointer.
+pub unsafe fn register_allocator(vtable: AllocatorVTable) -> bool {
+    let ptr = rust::Box::into_raw(rust::Box::new(vtable));
+    match REGISTERED_VTABLE.compare_exchange(
+        ptr::null_mut(),
+        ptr,
+        Ordering::AcqRel,
+        Ordering::Acquire,
+    ) {
+        | Ok(_) => true,
+        | Err(_) => {
+            drop(unsafe { rust::Box::from_raw(ptr) });
+            false
+        },
+    }
+}
+
+/// Exports the C function registering the allocator backing
+/// [`RegisteredAlloc`] (_c.f._ [`register_allocator()`]):
+///
+/// ```c
+/// /* Returns `false` if an allocator had already been registered. */
+/// bool register_allocator(AllocatorVTable_t vtable);
+/// ```
+///
+/// To be invoked once, _e.g._, at the root of the crate.
+#[macro_export]
+#[cfg_attr(rustfmt, rustfmt::skip)]
+macro_rules! ffi_export_register_allocator {() => (
+    const _: () = {
+        use $crate::c_alloc::AllocatorVTable;
+
+        /// Registers the allocator with which to allocate the values which
+        /// C code is then to release through `vtable.free`.
+        ///
+        /// Only the first registration succeeds (and returns `true`).
+        ///
+        /// `vtable.alloc(size, align)` MUST return either `NULL`, or a pointer
+        /// to at least `size` bytes, aligned to `align`, valid until given to
+        /// `vtable.free`; both functions being callable from any thread.
+        #[$crate::ffi_export]
+        unsafe fn register_allocator (
+            vtable: AllocatorVTable,
+        ) -> bool
+        {
+            unsafe { $crate::c_alloc::register_allocator(vtable) }
+        }
+    };
+)}
+
+fn registered_vtable() -> &'static AllocatorVTable {
+    let ptr = REGISTERED_VTABLE.load(Ordering::Acquire);
+    assert!(
+        ptr.is_null().not(),
+        "no allocator has been registered for `RegisteredAlloc`",
+    );
+    unsafe { &*ptr }
+}
+
+/// The [`Allocator`] registered at runtime through [`register_allocator()`].
+///
+/// # Panics
+///
+/// Allocating before an allocator has been registered panics.
+pub enum RegisteredAlloc {}
+
+unsafe impl Allocator for RegisteredAlloc {
+    const DEALLOCATOR: &'static str = "the `free` function of the registered `AllocatorVTable`";
+
+    fn alloc(layout: Layout) -> *mut u8 {
+        unsafe { (registered_vtable().alloc)(layout.size().max(1), layout.align()).cast() }
+    }
+
+    unsafe fn dealloc(
+        ptr: *mut u8,
+        _: Layout,
+    ) {
+        unsafe { (registered_vtable().free)(ptr.cast()) }
+    }
+}
+
+fn alloc_or_abort<A: Allocator>(layout: Layout) -> ptr::NonNull<u8> {
+    ptr::NonNull::new(A::alloc(layout)).unwrap_or_else(|| handle_alloc_error(layout))
+}
+
+#[cfg_attr(rustfmt, rustfmt::skip)]
+macro_rules! foreign_layouts {(
+    $(
+        $(#[$attr:meta])*
+        $Layout:ident [$($generics:tt)*] $(<$($T:ident),*>)? => $Inner:ty
+    ),* $(,)?
+) => (
+    $(
+        $(#[$attr])*
+        #[doc(hidden)]
+        #[repr(transparent)]
+        pub struct $Layout<$($generics)*>($Inner, PhantomData<fn() -> A>);
+
+        impl<$($generics)*> Clone for $Layout<$($($T,)*)? A> {
+            #[inline]
+            fn clone(self: &'_ Self) -> Self {
+                *self
+            }
+        }
+
+        impl<$($generics)*> Copy for $Layout<$($($T,)*)? A> {}
+
+        unsafe impl<$($generics)*> CType for $Layout<$($($T,)*)? A> {
+            type OPAQUE_KIND = OpaqueKind::Concrete;
+            __cfg_alloc__! {
+                fn describe() -> TypeDescriptor {
+                    <$Inner as CType>::describe()
+                }
+            }
+            __cfg_headers__! {
+                fn short_name() -> String {
+                    <$Inner as CType>::short_name()
+                }
+
+                fn define_self__impl(
+                    _language: &'_ dyn HeaderLanguage,
+                    _definer: &'_ mut dyn Definer,
+                ) -> io::Result<()>
+                {
+                    unimplemented!("directly did `define_self()`");
+                }
+
+                fn define_self(
+                    language: &'_ dyn HeaderLanguage,
+                    definer: &'_ mut dyn Definer,
+                ) -> io::Result<()>
+                {
+                    <$Inner as CType>::define_self(language, definer)
+                }
+
+                fn render(
+                    out: &'_ mut dyn io::Write,
+                    language: &'_ dyn HeaderLanguage,
+                ) -> io::Result<()>
+                {
+                    <$Inner as CType>::render(out, language)
+                }
+
+                fn metadata() -> &'static dyn Provider {
+                    &provide_with(|request| {
+                        request.give_if_requested::<Deallocator>(|| {
+                            Deallocator(A::DEALLOCATOR)
+                        });
+                    })
+                }
+            }
+        }
+
+        from_CType_impl_ReprC! {
+            @for[$($generics)*]
+            $Layout<$($($T,)*)? A>
+        }
+    )*
+)}
+
+foreign_layouts! {
+    /// The `CLayout` of a [`Box`]: that of a [`ThinBox`][crate::boxed::ThinBox].
+    Box_Layout[T: ReprC, A: Allocator]<T> => CLayoutOf<crate::boxed::ThinBox<T>>,
+    /// The `CLayout` of a [`Vec`]: that of a [`repr_c::Vec`].
+    Vec_Layout[T: ReprC, A: Allocator]<T> => CLayoutOf<repr_c::Vec<T>>,
+    /// The `CLayout` of a [`String`]: that of a [`repr_c::String`].
+    String_Layout[A: Allocator] => CLayoutOf<repr_c::String>,
+}
+
+/// Same as a [`repr_c::Box<T>`], but for its memory being managed by the
+/// [`Allocator`] `A`.
+#[repr(transparent)]
+pub struct Box<T, A: Allocator>(ptr::NonNull<T>, PhantomData<(T, A)>);
+
+unsafe impl<T: ReprC, A: Allocator> ReprC for Box<T, A> {
+    type CLayout = Box_Layout<T, A>;
+
+    #[inline]
+    fn is_valid(it: &'_ Box_Layout<T, A>) -> bool {
+        <crate::boxed::ThinBox<T> as ReprC>::is_valid(&it.0)
+    }
+}
+
+/// So that an `Option<Box<T, A>>` be a nullable pointer.
+unsafe impl<T: ReprC, A: Allocator> crate::layout::__HasNiche__ for Box<T, A> {
+    #[inline]
+    fn is_niche(it: &'_ Box_Layout<T, A>) -> bool {
+        <crate::boxed::ThinBox<T> as crate::layout::__HasNiche__>::is_niche(&it.0)
+    }
+}
+
+impl<T, A: Allocator> Box<T, A> {
+    pub fn new(value: T) -> Box<T, A> {
+        let ptr = alloc_or_abort::<A>(Layout::new::<T>()).cast::<T>();
+        unsafe {
+            ptr.as_ptr().write(value);
+        }
+        Self(ptr, PhantomData)
+    }
+
+    pub fn into_inner(self: Box<T, A>) -> T {
+        let this = mem::ManuallyDrop::new(self);
+        unsafe {
+            let value = this.0.as_ptr().read();
+            A::dealloc(this.0.as_ptr().cast(), Layout::new::<T>());
+            value
+        }
+    }
+}
+
+impl<T, A: Allocator> Drop for Box<T, A> {
+    fn drop(self: &'_ mut Box<T, A>) {
+        unsafe {
+            self.0.as_ptr().drop_in_place();
+            A::dealloc(self.0.as_ptr().cast(), Layout::new::<T>());
+        }
+    }
+}
+
+impl<T, A: Allocator> Deref for Box<T, A> {
+    type Target = T;
+
+    #[inline]
+    fn deref(self: &'_ Box<T, A>) -> &'_ T {
+        unsafe { self.0.as_ref() }
+    }
+}
+
+impl<T, A: Allocator> DerefMut for Box<T, A> {
+    #[inline]
+    fn deref_mut(self: &'_ mut Box<T, A>) -> &'_ mut T {
+        unsafe { self.0.as_mut() }
+    }
+}
+
+unsafe impl<T, A: Allocator> Send for Box<T, A> where rust::Box<T>: Send {}
+
+unsafe impl<T, A: Allocator> Sync for Box<T, A> where rust::Box<T>: Sync {}
+
+impl<T: Clone, A: Allocator> Clone for Box<T, A> {
+    #[inline]
+    fn clone(self: &'_ Self) -> Self {
+        Self::new(T::clone(self))
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for Box<T, A> {
+    fn fmt(
+        self: &'_ Self,
+        fmt: &'_ mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        T::fmt(self, fmt)
+    }
+}
+
+/// Same as a [`repr_c::Vec<T>`], but for its memory being managed by the
+/// [`Allocator`] `A`.
+///
+/// It is meant to transfer ownership of a sequence of values across the FFI
+/// boundary, and thus does not support growing: convert it from and into a
+/// standard [`Vec`][rust::Vec] to do so (which copies the elements).
+#[repr(C)]
+pub struct Vec<T, A: Allocator> {
+    ptr: ptr::NonNull<T>,
+    len: usize,
+    cap: usize,
+    _owns: PhantomData<(T, A)>,
+}
+
+unsafe impl<T: ReprC, A: Allocator> ReprC for Vec<T, A> {
+    type CLayout = Vec_Layout<T, A>;
+
+    #[inline]
+    fn is_valid(it: &'_ Vec_Layout<T, A>) -> bool {
+        <repr_c::Vec<T> as ReprC>::is_valid(&it.0)
+    }
+}
+
+impl<T, A: Allocator> Vec<T, A> {
+    #[inline]
+    fn layout(cap: usize) -> Layout {
+        Layout::array::<T>(cap).expect("capacity overflow")
+    }
+
+    #[inline]
+    pub fn len(self: &'_ Vec<T, A>) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(self: &'_ Vec<T, A>) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    pub fn as_slice(self: &'_ Vec<T, A>) -> &'_ [T] {
+        unsafe { ::core::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+
+    #[inline]
+    pub fn as_mut_slice(self: &'_ mut Vec<T, A>) -> &'_ mut [T] {
+        unsafe { ::core::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T, A: Allocator> From<rust::Vec<T>> for Vec<T, A> {
+    fn from(mut vec: rust::Vec<T>) -> Vec<T, A> {
+        let len = vec.len();
+        let ptr = alloc_or_abort::<A>(Self::layout(len)).cast::<T>();
+        unsafe {
+            ptr::copy_nonoverlapping(vec.as_ptr(), ptr.as_ptr(), len);
+            vec.set_len(0);
+        }
+        Self {
+            ptr,
+            len,
+            cap: len,
+            _owns: PhantomData,
+        }
+    }
+}
+
+impl<T, A: Allocator> From<Vec<T, A>> for rust::Vec<T> {
+    fn from(vec: Vec<T, A>) -> rust::Vec<T> {
+        let vec = mem::ManuallyDrop::new(vec);
+        let mut ret = rust::Vec::with_capacity(vec.len);
+        unsafe {
+            ptr::copy_nonoverlapping(vec.ptr.as_ptr(), ret.as_mut_ptr(), vec.len);
+            ret.set_len(vec.len);
+            A::dealloc(vec.ptr.as_ptr().cast(), Vec::<T, A>::layout(vec.cap));
+        }
+        ret
+    }
+}
+
+impl<T, A: Allocator> FromIterator<T> for Vec<T, A> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(items: I) -> Vec<T, A> {
+        items.into_iter().collect::<rust::Vec<T>>().into()
+    }
+}
+
+impl<T, A: Allocator> Drop for Vec<T, A> {
+    fn drop(self: &'_ mut Vec<T, A>) {
+        unsafe {
+            ptr::drop_in_place(self.as_mut_slice());
+            A::dealloc(self.ptr.as_ptr().cast(), Self::layout(self.cap));
+        }
+    }
+}
+
+impl<T, A: Allocator> Deref for Vec<T, A> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(self: &'_ Vec<T, A>) -> &'_ [T] {
+        self.as_slice()
+    }
+}
+
+impl<T, A: Allocator> DerefMut for Vec<T, A> {
+    #[inline]
+    fn deref_mut(self: &'_ mut Vec<T, A>) -> &'_ mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+unsafe impl<T, A: Allocator> Send for Vec<T, A> where rust::Vec<T>: Send {}
+
+unsafe impl<T, A: Allocator> Sync for Vec<T, A> where rust::Vec<T>: Sync {}
+
+impl<T: Clone, A: Allocator> Clone for Vec<T, A> {
+    #[inline]
+    fn clone(self: &'_ Self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for Vec<T, A> {
+    fn fmt(
+        self: &'_ Self,
+        fmt: &'_ mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        <[T] as fmt::Debug>::fmt(self, fmt)
+    }
+}
+
+/// Same as a [`repr_c::String`], but for its memory being managed by the
+/// [`Allocator`] `A`.
+#[repr(transparent)]
+pub struct String<A: Allocator>(Vec<u8, A>);
+
+unsafe impl<A: Allocator> ReprC for String<A> {
+    type CLayout = String_Layout<A>;
+
+    #[inline]
+    fn is_valid(it: &'_ String_Layout<A>) -> bool {
+        <repr_c::String as ReprC>::is_valid(&it.0)
+    }
+}
+
+impl<A: Allocator> String<A> {
+    #[inline]
+    pub fn as_str(self: &'_ String<A>) -> &'_ str {
+        unsafe { ::core::str::from_utf8_unchecked(self.0.as_slice()) }
+    }
+}
+
+impl<A: Allocator> From<rust::String> for String<A> {
+    #[inline]
+    fn from(s: rust::String) -> String<A> {
+        Self(rust::Vec::from(s).into())
+    }
+}
+
+impl<'lt, A: Allocator> From<&'lt str> for String<A> {
+    #[inline]
+    fn from(s: &'lt str) -> String<A> {
+        Self(s.as_bytes().to_vec().into())
+    }
+}
+
+impl<A: Allocator> From<String<A>> for rust::String {
+    #[inline]
+    fn from(String(bytes): String<A>) -> rust::String {
+        unsafe { rust::String::from_utf8_unchecked(bytes.into()) }
+    }
+}
+
+impl<A: Allocator> Deref for String<A> {
+    type Target = str;
+
+    #[inline]
+    fn deref(self: &'_ String<A>) -> &'_ str {
+        self.as_str()
+    }
+}
+
+impl<A: Allocator> Clone for String<A> {
+    #[inline]
+    fn clone(self: &'_ Self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<A: Allocator> fmt::Debug for String<A> {
+    fn fmt(
+        self: &'_ Self,
+        fmt: &'_ mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        <str as fmt::Debug>::fmt(self, fmt)
+    }
+}
+
+impl<A: Allocator> fmt::Display for String<A> {
+    fn fmt(
+        self: &'_ Self,
+        fmt: &'_ mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        <str as fmt::Display>::fmt(self, fmt)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn c_alloc() {
+    let boxed = Box::<_, CAlloc>::new(42_u64);
+    assert_eq!(*boxed.clone(), 42);
+    assert_eq!(boxed.into_inner(), 42);
+
+    let vec = Vec::<_, CAlloc>::from(vec![1, 2, 3]);
+    assert_eq!(vec[..], [1, 2, 3]);
+    assert_eq!(rust::Vec::from(vec.clone()), [1, 2, 3]);
+    assert!(Vec::<u8, CAlloc>::from(vec![]).is_empty());
+
+    let s = String::<CAlloc>::from("Hello, World!");
+    assert_eq!(rust::String::from(s.clone()), "Hello, World!");
+    // What C code would do.
+    let ptr = s.as_ptr();
+    mem::forget(s);
+    unsafe { ::libc::free(ptr as *mut c_void) };
+}
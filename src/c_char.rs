@@ -32,6 +32,11 @@ const _: () = {
 
 unsafe impl CType for c_char {
     type OPAQUE_KIND = OpaqueKind::Concrete;
+    __cfg_alloc__! {
+        fn describe() -> crate::layout::TypeDescriptor {
+            crate::layout::TypeDescriptor::new::<Self>(crate::layout::TypeKind::CChar)
+        }
+    }
     __cfg_headers__! {
         fn short_name() -> String {
             "char".into()
@@ -61,3 +66,62 @@ unsafe impl CType for c_char {
 from_CType_impl_ReprC! {
     c_char
 }
+
+/// A `ReprC` _standalone_ type with the same layout and ABI as a UTF-16 code
+/// unit, _i.e._, C11's `char16_t` (from `<uchar.h>`).
+///
+/// It is to [`c_char`] what `u16` is to `u8`: by using this type, you
+/// guarantee that `char16_t` (and `char` in C#) be used in the headers.
+#[cfg_attr(feature = "stabby", stabby::stabby)]
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, Default, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub struct c_char16(pub u16);
+
+unsafe impl CType for c_char16 {
+    type OPAQUE_KIND = OpaqueKind::Concrete;
+    __cfg_alloc__! {
+        fn describe() -> crate::layout::TypeDescriptor {
+            crate::layout::TypeDescriptor::new::<Self>(crate::layout::TypeKind::Char16)
+        }
+    }
+    __cfg_headers__! {
+        fn short_name() -> String {
+            "char16".into()
+        }
+
+        fn define_self__impl(
+            _language: &dyn HeaderLanguage,
+            _definer: &mut dyn Definer,
+        ) -> io::Result<()>
+        {
+            unimplemented!("directly did `define_self()`");
+        }
+
+        fn define_self(
+            language: &dyn HeaderLanguage,
+            definer: &mut dyn Definer,
+        ) -> io::Result<()>
+        {
+            language.define_primitive_ty(
+                language,
+                definer,
+                primitives::Primitive::Char16,
+            )
+        }
+
+        fn render(
+            out: &mut dyn io::Write,
+            language: &dyn HeaderLanguage,
+        ) -> io::Result<()>
+        {
+            language.emit_primitive_ty(
+                out,
+                primitives::Primitive::Char16,
+            )
+        }
+    }
+}
+
+from_CType_impl_ReprC! {
+    c_char16
+}
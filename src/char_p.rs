@@ -245,6 +245,172 @@ impl fmt::Debug for char_p_raw {
     }
 }
 
+ReprC! {
+    #[repr(transparent)]
+    #[derive(Clone, Copy)]
+    /// A `#[repr(c)]` null-terminated string of bytes, with no guarantees
+    /// _w.r.t._ its encoding, for compatibility with the C `char const *` API
+    /// when the contents may not be UTF-8 (_e.g._, file paths, or legacy
+    /// encodings).
+    ///
+    /// This is a **borrowed** version, _i.e._, with the semantics of
+    /// `&'lt CStr`, but for it being a _slim_ pointer.
+    ///
+    /// The headers render it exactly as a [`char_p_ref`].
+    pub
+    struct char_p_bytes['lt,] (
+        ptr::NonNullRef<c_char>,
+        PhantomCovariantLifetime<'lt>,
+    );
+}
+
+impl char_p_bytes<'static> {
+    pub const EMPTY: Self = unsafe {
+        Self::from_ptr_unchecked(ptr::NonNull::new_unchecked({
+            const IT: &u8 = &NUL;
+            IT as *const u8 as *mut u8
+        }))
+    };
+}
+
+impl<'lt> char_p_bytes<'lt> {
+    pub const unsafe fn from_ptr_unchecked(ptr: ptr::NonNull<u8>) -> Self {
+        Self(
+            ptr::NonNullRef(ptr.cast()),
+            PhantomCovariantLifetime::<'static>(PhantomData),
+        )
+    }
+
+    #[inline]
+    pub fn to_bytes(self: char_p_bytes<'lt>) -> &'lt [u8] {
+        let bytes = self.to_bytes_with_null();
+        &bytes[.. bytes.len() - 1]
+    }
+
+    #[inline]
+    pub fn to_bytes_with_null(self: char_p_bytes<'lt>) -> &'lt [u8] {
+        let ptr: *const u8 = self.0.as_ptr().cast();
+        unsafe {
+            let mut len = 0;
+            while *ptr.add(len) != NUL {
+                len += 1;
+            }
+            slice::from_raw_parts(ptr, len + 1)
+        }
+    }
+
+    /// Fails if the bytes are not valid UTF-8.
+    #[inline]
+    pub fn to_str(self: char_p_bytes<'lt>) -> Result<&'lt str, ::core::str::Utf8Error> {
+        ::core::str::from_utf8(self.to_bytes())
+    }
+
+    /// Fails if the bytes are not valid UTF-8.
+    #[inline]
+    pub fn to_char_p(self: char_p_bytes<'lt>) -> Result<char_p_ref<'lt>, ::core::str::Utf8Error> {
+        self.to_str()?;
+        Ok(unsafe { mem::transmute::<char_p_bytes<'lt>, char_p_ref<'lt>>(self) })
+    }
+
+    cfg_alloc! {
+        /// Decodes the bytes as UTF-8, replacing invalid sequences with
+        /// [`char::REPLACEMENT_CHARACTER`].
+        #[inline]
+        pub
+        fn to_string_lossy (self: char_p_bytes<'lt>)
+          -> ::alloc::borrow::Cow<'lt, str>
+        {
+            rust::String::from_utf8_lossy(self.to_bytes())
+        }
+    }
+
+    /// The bytes, as a (Unix) [`OsStr`][::std::ffi::OsStr], without any
+    /// conversion.
+    #[cfg(all(unix, feature = "std"))]
+    #[cfg_attr(all(docs, feature = "nightly"), doc(cfg(all(unix, feature = "std"))))]
+    #[inline]
+    pub fn to_os_str(self: char_p_bytes<'lt>) -> &'lt ::std::ffi::OsStr {
+        ::std::os::unix::ffi::OsStrExt::from_bytes(self.to_bytes())
+    }
+
+    /// The bytes, as a (Unix) [`Path`][::std::path::Path], without any
+    /// conversion.
+    #[cfg(all(unix, feature = "std"))]
+    #[cfg_attr(all(docs, feature = "nightly"), doc(cfg(all(unix, feature = "std"))))]
+    #[inline]
+    pub fn to_path(self: char_p_bytes<'lt>) -> &'lt ::std::path::Path {
+        self.to_os_str().as_ref()
+    }
+}
+
+impl<'lt> From<char_p_ref<'lt>> for char_p_bytes<'lt> {
+    #[inline]
+    fn from(it: char_p_ref<'lt>) -> char_p_bytes<'lt> {
+        unsafe { mem::transmute(it) }
+    }
+}
+
+impl<'lt> TryFrom<char_p_bytes<'lt>> for char_p_ref<'lt> {
+    type Error = ::core::str::Utf8Error;
+
+    #[inline]
+    fn try_from(it: char_p_bytes<'lt>) -> Result<char_p_ref<'lt>, ::core::str::Utf8Error> {
+        it.to_char_p()
+    }
+}
+
+impl<'lt> TryFrom<&'lt [u8]> for char_p_bytes<'lt> {
+    type Error = InvalidNulTerminator<()>;
+
+    /// The `bytes` must end with a (single) `NUL` terminator.
+    fn try_from(bytes: &'lt [u8]) -> Result<char_p_bytes<'lt>, InvalidNulTerminator<()>> {
+        match bytes.iter().position(|&b| b == NUL) {
+            | Some(idx) if idx + 1 == bytes.len() => unsafe {
+                Ok(Self::from_ptr_unchecked(ptr::NonNull::from(&bytes[0])))
+            },
+            | _ => Err(InvalidNulTerminator(())),
+        }
+    }
+}
+
+impl<'lt> From<&'lt ::core::ffi::CStr> for char_p_bytes<'lt> {
+    #[inline]
+    fn from(s: &'lt ::core::ffi::CStr) -> char_p_bytes<'lt> {
+        unsafe { Self::from_ptr_unchecked(ptr::NonNull::new(s.as_ptr() as _).unwrap()) }
+    }
+}
+
+impl<'lt> From<char_p_bytes<'lt>> for &'lt ::core::ffi::CStr {
+    #[inline]
+    fn from(it: char_p_bytes<'lt>) -> &'lt ::core::ffi::CStr {
+        unsafe { ::core::ffi::CStr::from_bytes_with_nul_unchecked(it.to_bytes_with_null()) }
+    }
+}
+
+impl fmt::Debug for char_p_bytes<'_> {
+    fn fmt(
+        self: &'_ Self,
+        fmt: &'_ mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(fmt, "\"{}\"", self.to_bytes().escape_ascii())
+    }
+}
+
+impl<'lt> Eq for char_p_bytes<'lt> {}
+impl<'lt> PartialEq for char_p_bytes<'lt> {
+    #[inline]
+    fn eq(
+        self: &'_ Self,
+        other: &'_ Self,
+    ) -> bool {
+        self.to_bytes() == other.to_bytes()
+    }
+}
+
+unsafe impl Send for char_p_bytes<'_> where for<'lt> &'lt [u8]: Send {}
+
+unsafe impl Sync for char_p_bytes<'_> where for<'lt> &'lt [u8]: Sync {}
+
 cfg_alloc! {
     /// Constructs a new `char_p::Box` off a stringy input.
     ///
@@ -417,6 +583,30 @@ cfg_alloc! {
         }
     }
 
+    #[cfg(feature = "serde")]
+    impl serde::Serialize for char_p_boxed {
+        fn serialize<S : serde::Serializer> (
+            self: &'_ Self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>
+        {
+            serializer.serialize_str(self.to_str())
+        }
+    }
+
+    /// Fails on strings with inner `NUL` bytes.
+    #[cfg(feature = "serde")]
+    impl<'de> serde::Deserialize<'de> for char_p_boxed {
+        fn deserialize<D : serde::Deserializer<'de>> (
+            deserializer: D,
+        ) -> Result<Self, D::Error>
+        {
+            let s: rust::String = serde::Deserialize::deserialize(deserializer)?;
+            char_p_boxed::try_from(s)
+                .map_err(serde::de::Error::custom)
+        }
+    }
+
     impl Drop for char_p_boxed {
         fn drop (self: &'_ mut char_p_boxed)
         {
@@ -575,3 +765,488 @@ cfg_std! {
         }
     }
 }
+
+/// Whether `it` is a non-`NULL` and well-aligned pointer.
+///
+/// The pointee is not read: the `NULL`-terminated array, and the UTF-8
+/// encoding of its strings, are trusted, as with [`char_p_ref`]. See
+/// [`char_p_array::try_strs()`] to check the latter.
+fn is_valid_char_p_array(&it: &'_ *const *const c_char) -> bool {
+    it.is_null().not() && (it as usize) % ::core::mem::align_of::<*const c_char>() == 0
+}
+
+/// The error of the checked `try_from_raw()` constructors of
+/// [`char_p_array`] and [`char_p_boxed_array`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidCharPArray {
+    /// The array pointer is `NULL` or misaligned.
+    InvalidPointer,
+    /// The array is not made of `len` non-`NULL` strings followed by a `NULL`
+    /// terminator.
+    InvalidNulTerminator,
+    /// The string at `index` is not valid UTF-8.
+    InvalidUtf8 {
+        index: usize,
+        error: ::core::str::Utf8Error,
+    },
+}
+
+impl fmt::Display for InvalidCharPArray {
+    fn fmt(
+        self: &'_ Self,
+        fmt: &'_ mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match *self {
+            | Self::InvalidPointer => fmt::Display::fmt("Null or misaligned array pointer", fmt),
+            | Self::InvalidNulTerminator => {
+                fmt::Display::fmt("Null pointer not at the expected terminating position", fmt)
+            },
+            | Self::InvalidUtf8 { index, error } => {
+                write!(fmt, "Invalid UTF-8 in the string at index {index}: {error}")
+            },
+        }
+    }
+}
+
+cfg_std! {
+    impl ::std::error::Error for InvalidCharPArray {}
+}
+
+/// A `#[repr(C)]` `NULL`-terminated array of [`char_p_ref`]s, _i.e._, an
+/// `argv`-style `char const * const *`.
+///
+/// This is a **borrowed** version, _i.e._, with the semantics of
+/// `&'lt [char_p::Ref<'lt>]`, but for it being a _slim_ pointer.
+///
+/// # Validity
+///
+/// When received from C, only the array pointer is checked (to be
+/// non-`NULL` and well-aligned): since [`ReprC::is_valid`] is not to read
+/// the pointee, the `NULL` terminator, and the UTF-8 encoding of each
+/// string, are trusted. To check them all, use [`Self::try_from_raw()`]
+/// (or [`Self::try_strs()`] to lazily check the encoding).
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct char_p_array<'lt>(
+    ptr::NonNullRef<Option<char_p_ref<'lt>>>,
+    PhantomCovariantLifetime<'lt>,
+);
+
+unsafe impl ReprC for char_p_array<'_> {
+    type CLayout = *const *const c_char;
+
+    /// Only checks the pointer itself, since the array is `NULL`-terminated.
+    #[inline]
+    fn is_valid(it: &'_ *const *const c_char) -> bool {
+        is_valid_char_p_array(it)
+    }
+}
+
+impl char_p_array<'static> {
+    pub const EMPTY: Self = Self(
+        ptr::NonNullRef(unsafe {
+            ptr::NonNull::new_unchecked({
+                const IT: &Option<char_p_ref<'static>> = &None;
+                IT as *const _ as *mut _
+            })
+        }),
+        PhantomCovariantLifetime(PhantomData),
+    );
+}
+
+impl<'lt> char_p_array<'lt> {
+    /// Checked conversion from a C `char const * const *` array of `len`
+    /// strings (plus the `NULL` terminator): checks the pointer, the position
+    /// of the terminator, and the UTF-8 encoding of every string.
+    ///
+    /// # Safety
+    ///
+    ///   - if non-`NULL` and well-aligned, `ptr` must be readable for `len + 1`
+    ///     pointers, during `'lt`;
+    ///
+    ///   - each of the first `len` of them, if non-`NULL`, must point to a
+    ///     `NUL`-terminated string, readable (and unmodified) during `'lt`.
+    pub unsafe fn try_from_raw(
+        ptr: *const *const c_char,
+        len: usize,
+    ) -> Result<char_p_array<'lt>, InvalidCharPArray> {
+        if is_valid_char_p_array(&ptr).not() {
+            return Err(InvalidCharPArray::InvalidPointer);
+        }
+        let (&terminator, strings) = unsafe { slice::from_raw_parts(ptr, len + 1) }
+            .split_last()
+            .unwrap();
+        if terminator.is_null().not() || strings.iter().any(|s| s.is_null()) {
+            return Err(InvalidCharPArray::InvalidNulTerminator);
+        }
+        // Safety: `char_p_ref` is a non-`NULL` `*const c_char`.
+        let strings: &'lt [char_p_ref<'lt>] = unsafe { slice::from_raw_parts(ptr.cast(), len) };
+        if let Some((index, error)) = strings.iter().enumerate().find_map(|(index, s)| {
+            ::core::str::from_utf8(s.to_bytes())
+                .err()
+                .map(|error| (index, error))
+        }) {
+            return Err(InvalidCharPArray::InvalidUtf8 { index, error });
+        }
+        Ok(Self(
+            ptr::NonNullRef(unsafe { ptr::NonNull::new_unchecked(ptr.cast_mut().cast()) }),
+            PhantomCovariantLifetime(PhantomData),
+        ))
+    }
+
+    /// The strings, without the `NULL` terminator.
+    #[inline]
+    pub fn as_slice(self: char_p_array<'lt>) -> &'lt [char_p_ref<'lt>] {
+        let ptr = self.0.as_ptr();
+        unsafe {
+            let mut len = 0;
+            while (*ptr.add(len)).is_some() {
+                len += 1;
+            }
+            // Safety: `Option<char_p_ref>` has the same layout as
+            // `char_p_ref`, and the first `len` elements are `Some`.
+            slice::from_raw_parts(ptr.cast(), len)
+        }
+    }
+
+    #[inline]
+    pub fn len(self: char_p_array<'lt>) -> usize {
+        self.as_slice().len()
+    }
+
+    #[inline]
+    pub fn is_empty(self: char_p_array<'lt>) -> bool {
+        unsafe { self.0.as_ptr().read().is_none() }
+    }
+
+    #[inline]
+    pub fn iter(self: char_p_array<'lt>) -> impl Iterator<Item = char_p_ref<'lt>> + 'lt {
+        self.as_slice().iter().copied()
+    }
+
+    #[inline]
+    pub fn strs(self: char_p_array<'lt>) -> impl Iterator<Item = &'lt str> + 'lt {
+        self.iter().map(char_p_ref::to_str)
+    }
+
+    /// Same as [`Self::strs()`], but for lazily checking each string to be
+    /// valid UTF-8 (which is otherwise trusted), _e.g._, for an array received
+    /// from C.
+    #[inline]
+    pub fn try_strs(
+        self: char_p_array<'lt>,
+    ) -> impl Iterator<Item = Result<&'lt str, ::core::str::Utf8Error>> + 'lt {
+        self.iter().map(|s| ::core::str::from_utf8(s.to_bytes()))
+    }
+}
+
+impl<'lt> TryFrom<&'lt [Option<char_p_ref<'lt>>]> for char_p_array<'lt> {
+    type Error = InvalidNulTerminator<()>;
+
+    /// The `array` must end with a (single) `None` terminator.
+    fn try_from(
+        array: &'lt [Option<char_p_ref<'lt>>],
+    ) -> Result<char_p_array<'lt>, InvalidNulTerminator<()>> {
+        match array.iter().position(Option::is_none) {
+            | Some(idx) if idx + 1 == array.len() => Ok(Self(
+                ptr::NonNullRef::from(&array[0]),
+                PhantomCovariantLifetime(PhantomData),
+            )),
+            | _ => Err(InvalidNulTerminator(())),
+        }
+    }
+}
+
+impl fmt::Debug for char_p_array<'_> {
+    fn fmt(
+        self: &'_ Self,
+        fmt: &'_ mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        fmt.debug_list().entries(self.iter()).finish()
+    }
+}
+
+unsafe impl Send for char_p_array<'_> where for<'lt> &'lt [char_p_ref<'lt>]: Send {}
+
+unsafe impl Sync for char_p_array<'_> where for<'lt> &'lt [char_p_ref<'lt>]: Sync {}
+
+cfg_alloc! {
+    /// A `#[repr(C)]` `NULL`-terminated array of [`char_p_boxed`]s, _i.e._,
+    /// an `argv`-style `char const * const *`.
+    ///
+    /// This is an **owned** / heap-allocated version, much like
+    /// `Box<[char_p::Box]>` but for it being a _slim_ pointer.
+    ///
+    /// # Validity
+    ///
+    /// As with [`char_p_array`], only the array pointer is checked when
+    /// received from C, the rest being trusted: see [`Self::try_from_raw()`]
+    /// for a checked constructor.
+    #[repr(transparent)]
+    pub
+    struct char_p_boxed_array (
+        ptr::NonNullOwned<Option<char_p_boxed>>,
+    );
+
+    unsafe
+    impl ReprC
+        for char_p_boxed_array
+    {
+        type CLayout = *const *const c_char;
+
+        /// Only checks the pointer itself, since the array is `NULL`-terminated.
+        #[inline]
+        fn is_valid (it: &'_ *const *const c_char)
+          -> bool
+        {
+            is_valid_char_p_array(it)
+        }
+    }
+
+    unsafe // Safety: inherited from `Box<[char_p_boxed]>`.
+    impl Send
+        for char_p_boxed_array
+    where
+        rust::Box<[char_p_boxed]> : Send,
+    {}
+
+    unsafe // Safety: inherited from `Box<[char_p_boxed]>`.
+    impl Sync
+        for char_p_boxed_array
+    where
+        rust::Box<[char_p_boxed]> : Sync,
+    {}
+
+    impl char_p_boxed_array {
+        /// Checked version of taking back the ownership of an array, of `len`
+        /// strings (plus the `NULL` terminator), which was handed to C: see
+        /// [`char_p_array::try_from_raw()`].
+        ///
+        /// On error, the array is left untouched (and not freed).
+        ///
+        /// # Safety
+        ///
+        ///   - same as for [`char_p_array::try_from_raw()`];
+        ///
+        ///   - on success, the array and its strings must have been allocated
+        ///     as a `char_p_boxed_array` (_e.g._, they come from
+        ///     [`into_raw`][crate::layout::into_raw]), whose ownership is
+        ///     transferred to the returned value.
+        pub
+        unsafe
+        fn try_from_raw (ptr: *const *const c_char, len: usize)
+          -> Result<char_p_boxed_array, InvalidCharPArray>
+        {
+            unsafe { char_p_array::try_from_raw(ptr, len) }?;
+            Ok(Self(unsafe { ptr::NonNull::new_unchecked(ptr.cast_mut().cast()) }.into()))
+        }
+
+        #[inline]
+        pub
+        fn as_ref (self: &'_ char_p_boxed_array)
+          -> char_p_array<'_>
+        {
+            unsafe {
+                mem::transmute(self.0.as_ref())
+            }
+        }
+
+        #[inline]
+        pub
+        fn iter (self: &'_ char_p_boxed_array)
+          -> impl Iterator<Item = char_p_ref<'_>> + '_
+        {
+            self.as_ref().iter()
+        }
+
+        #[inline]
+        pub
+        fn strs (self: &'_ char_p_boxed_array)
+          -> impl Iterator<Item = &'_ str> + '_
+        {
+            self.as_ref().strs()
+        }
+
+        /// See [`char_p_array::try_strs()`].
+        #[inline]
+        pub
+        fn try_strs (self: &'_ char_p_boxed_array)
+          -> impl Iterator<Item = Result<&'_ str, ::core::str::Utf8Error>> + '_
+        {
+            self.as_ref().try_strs()
+        }
+
+        #[inline]
+        pub
+        fn len (self: &'_ char_p_boxed_array)
+          -> usize
+        {
+            self.as_ref().len()
+        }
+
+        #[inline]
+        pub
+        fn is_empty (self: &'_ char_p_boxed_array)
+          -> bool
+        {
+            self.as_ref().is_empty()
+        }
+
+        pub
+        fn into_vec (self: char_p_boxed_array)
+          -> rust::Vec<char_p_boxed>
+        {
+            let num_elems_with_null = self.len() + 1;
+            let ptr = mem::ManuallyDrop::new(self).0.as_mut_ptr();
+            let boxed: rust::Box<[Option<char_p_boxed>]> = unsafe {
+                rust::Box::from_raw(slice::from_raw_parts_mut(
+                    ptr,
+                    num_elems_with_null,
+                ))
+            };
+            rust::Vec::from(boxed).into_iter().flatten().collect()
+        }
+    }
+
+    impl From<rust::Vec<char_p_boxed>>
+        for char_p_boxed_array
+    {
+        fn from (strings: rust::Vec<char_p_boxed>)
+          -> char_p_boxed_array
+        {
+            strings.into_iter().collect()
+        }
+    }
+
+    impl FromIterator<char_p_boxed>
+        for char_p_boxed_array
+    {
+        fn from_iter<I : IntoIterator<Item = char_p_boxed>> (strings: I)
+          -> char_p_boxed_array
+        {
+            let boxed: rust::Box<[Option<char_p_boxed>]> =
+                strings .into_iter()
+                        .map(Some)
+                        .chain([None])
+                        .collect()
+            ;
+            let ptr = rust::Box::into_raw(boxed) as *mut Option<char_p_boxed>;
+            Self(
+                ptr::NonNullOwned(ptr::NonNull::new(ptr).unwrap(), PhantomData),
+            )
+        }
+    }
+
+    impl TryFrom<rust::Vec<rust::String>>
+        for char_p_boxed_array
+    {
+        type Error = InvalidNulTerminator<rust::String>;
+
+        /// Fails if any string contains an interior (non-last) `NUL` byte.
+        fn try_from (strings: rust::Vec<rust::String>)
+          -> Result<char_p_boxed_array, InvalidNulTerminator<rust::String>>
+        {
+            strings
+                .into_iter()
+                .map(char_p_boxed::try_from)
+                .collect()
+        }
+    }
+
+    impl Drop
+        for char_p_boxed_array
+    {
+        fn drop (self: &'_ mut char_p_boxed_array)
+        {
+            let num_elems_with_null = self.len() + 1;
+            drop::<rust::Box<[Option<char_p_boxed>]>>(unsafe {
+                rust::Box::from_raw(slice::from_raw_parts_mut(
+                    self.0.as_mut_ptr(),
+                    num_elems_with_null,
+                ))
+            });
+        }
+    }
+
+    impl Clone
+        for char_p_boxed_array
+    {
+        fn clone (self: &'_ char_p_boxed_array)
+          -> char_p_boxed_array
+        {
+            self.iter().map(char_p_ref::to_owned).collect()
+        }
+    }
+
+    impl fmt::Debug
+        for char_p_boxed_array
+    {
+        fn fmt (self: &'_ Self, fmt: &'_ mut fmt::Formatter<'_>)
+          -> fmt::Result
+        {
+            fmt::Debug::fmt(&self.as_ref(), fmt)
+        }
+    }
+}
+
+#[test]
+fn bytes() {
+    let non_utf8 = char_p_bytes::try_from(&b"caf\xe9\0"[..]).unwrap();
+    assert_eq!(non_utf8.to_bytes(), b"caf\xe9");
+    assert!(non_utf8.to_str().is_err());
+    assert!(char_p_ref::try_from(non_utf8).is_err());
+    assert!(char_p_bytes::try_from(&b"a\0b\0"[..]).is_err());
+    #[cfg(feature = "alloc")]
+    assert_eq!(non_utf8.to_string_lossy(), "caf\u{fffd}");
+    #[cfg(all(unix, feature = "std"))]
+    assert_eq!(non_utf8.to_path().as_os_str().len(), 4);
+
+    let utf8 = char_p_bytes::from(char_p_ref::try_from("hi\0").unwrap());
+    assert_eq!(char_p_ref::try_from(utf8).unwrap().to_str(), "hi");
+    assert_eq!(char_p_bytes::EMPTY.to_bytes_with_null(), b"\0");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn arrays() {
+    let argv = char_p_boxed_array::try_from(vec!["foo".to_owned(), "bär".to_owned()]).unwrap();
+    assert_eq!(argv.strs().collect::<rust::Vec<_>>(), ["foo", "bär"]);
+    let raw = unsafe { crate::layout::into_raw(argv.clone()) };
+    assert!(<char_p_boxed_array as ReprC>::is_valid(&raw));
+    let argv2 = unsafe { crate::layout::from_raw_unchecked::<char_p_boxed_array>(raw) };
+    assert_eq!(argv2.into_vec(), argv.as_ref().iter().map(char_p_ref::to_owned).collect::<rust::Vec<_>>());
+
+    assert!(char_p_boxed_array::try_from(vec!["a\0b".to_owned()]).is_err());
+    assert!(char_p_array::EMPTY.is_empty());
+    assert!(bool::not(<char_p_array<'_> as ReprC>::is_valid(&ptr::null())));
+    let non_utf8: [*const c_char; 3] = [
+        b"ok\0".as_ptr().cast(),
+        b"\xff\0".as_ptr().cast(),
+        ptr::null(),
+    ];
+    let non_utf8 =
+        unsafe { crate::layout::from_raw::<char_p_array<'_>>(non_utf8.as_ptr()) }.unwrap();
+    let mut strs = non_utf8.try_strs();
+    assert_eq!(strs.next().unwrap(), Ok("ok"));
+    assert!(strs.next().unwrap().is_err());
+    assert!(strs.next().is_none());
+
+    let ok: [*const c_char; 2] = [b"ok\0".as_ptr().cast(), ptr::null()];
+    let ok = unsafe { char_p_array::try_from_raw(ok.as_ptr(), 1) }.unwrap();
+    assert_eq!(ok.strs().collect::<rust::Vec<_>>(), ["ok"]);
+    assert!(matches!(
+        unsafe { char_p_array::try_from_raw(non_utf8.as_slice().as_ptr().cast(), 2) },
+        Err(InvalidCharPArray::InvalidUtf8 { index: 1, .. }),
+    ));
+    assert_eq!(
+        unsafe { char_p_array::try_from_raw(ptr::null(), 0) }.err(),
+        Some(InvalidCharPArray::InvalidPointer),
+    );
+    let raw = unsafe { crate::layout::into_raw(argv) };
+    assert_eq!(
+        unsafe { char_p_boxed_array::try_from_raw(raw, 1) }.err(),
+        Some(InvalidCharPArray::InvalidNulTerminator),
+    );
+    let argv = unsafe { char_p_boxed_array::try_from_raw(raw, 2) }.unwrap();
+    assert_eq!(argv.strs().collect::<rust::Vec<_>>(), ["foo", "bär"]);
+}
@@ -12,6 +12,8 @@ pub use dyn_drop::*;
 
 pub mod dyn_drop;
 
+pub mod io;
+
 pub use self::ty::Erased as ErasedTy;
 
 #[super::derive_ReprC]
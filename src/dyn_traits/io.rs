@@ -0,0 +1,294 @@
+//! FFI-safe counterparts of [`std::io::Read`], [`std::io::Write`] and
+//! [`std::io::Seek`], so as to stream data between C and Rust.
+//!
+//!   - Any `std::io` implementor can be handed to C as a
+//!     `VirtualPtr<dyn FfiRead>` (and so on), _e.g._, through `Box::new(reader).into()`;
+//!
+//!   - Conversely, a `VirtualPtr<dyn FfiRead>` whose vtable has been provided by C (_e.g._, to
+//!     read from a C socket) can be used as a Rust `impl std::io::Read` by wrapping it in an
+//!     [`IoAdapter`].
+//!
+//! Errors cross the FFI boundary as an [`IoStatus`] code, with the actual
+//! results being written to out-parameters.
+
+use ::std::io;
+
+use super::*;
+
+macro_rules! io_statuses {(
+    $(
+        $Kind:ident = $discriminant:literal
+    ),* $(,)?
+) => (
+    /// FFI-safe status of an I/O operation: `Ok`, or an error code mirroring an
+    /// [`io::ErrorKind`].
+    ///
+    /// Kinds without a dedicated code are mapped to `Other`.
+    #[derive_ReprC]
+    #[repr(u8)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum IoStatus {
+        Ok = 0,
+        Other = 1,
+        $(
+            $Kind = $discriminant,
+        )*
+    }
+
+    impl From<io::ErrorKind> for IoStatus {
+        fn from(kind: io::ErrorKind) -> IoStatus {
+            match kind {
+                $(
+                    | io::ErrorKind::$Kind => IoStatus::$Kind,
+                )*
+                | _ => IoStatus::Other,
+            }
+        }
+    }
+
+    impl IoStatus {
+        pub fn into_result(self: IoStatus) -> io::Result<()> {
+            let kind = match self {
+                | IoStatus::Ok => return Ok(()),
+                | IoStatus::Other => io::ErrorKind::Other,
+                $(
+                    | IoStatus::$Kind => io::ErrorKind::$Kind,
+                )*
+            };
+            Err(kind.into())
+        }
+    }
+)}
+
+io_statuses! {
+    NotFound = 2,
+    PermissionDenied = 3,
+    ConnectionRefused = 4,
+    ConnectionReset = 5,
+    ConnectionAborted = 6,
+    NotConnected = 7,
+    AddrInUse = 8,
+    AddrNotAvailable = 9,
+    BrokenPipe = 10,
+    AlreadyExists = 11,
+    WouldBlock = 12,
+    InvalidInput = 13,
+    InvalidData = 14,
+    TimedOut = 15,
+    WriteZero = 16,
+    Interrupted = 17,
+    Unsupported = 18,
+    UnexpectedEof = 19,
+    OutOfMemory = 20,
+}
+
+impl IoStatus {
+    fn of<T>(
+        result: io::Result<T>,
+        out: &'_ mut T,
+    ) -> IoStatus {
+        match result {
+            | Ok(value) => {
+                *out = value;
+                IoStatus::Ok
+            },
+            | Err(err) => err.kind().into(),
+        }
+    }
+}
+
+/// Models an [`io::Read`].
+#[derive_ReprC(dyn)]
+pub trait FfiRead {
+    /// On success, the number of bytes read into `buf` is written to `out_read`.
+    fn dyn_read(
+        self: &'_ mut Self,
+        buf: c_slice::Mut<'_, u8>,
+        out_read: &'_ mut usize,
+    ) -> IoStatus;
+}
+
+impl<T: io::Read> FfiRead for T {
+    fn dyn_read(
+        self: &'_ mut Self,
+        buf: c_slice::Mut<'_, u8>,
+        out_read: &'_ mut usize,
+    ) -> IoStatus {
+        IoStatus::of(self.read(buf.as_slice()), out_read)
+    }
+}
+
+/// Models an [`io::Write`].
+#[derive_ReprC(dyn)]
+pub trait FfiWrite {
+    /// On success, the number of bytes written from `buf` is written to `out_written`.
+    fn dyn_write(
+        self: &'_ mut Self,
+        buf: c_slice::Ref<'_, u8>,
+        out_written: &'_ mut usize,
+    ) -> IoStatus;
+
+    fn dyn_flush(self: &'_ mut Self) -> IoStatus;
+}
+
+impl<T: io::Write> FfiWrite for T {
+    fn dyn_write(
+        self: &'_ mut Self,
+        buf: c_slice::Ref<'_, u8>,
+        out_written: &'_ mut usize,
+    ) -> IoStatus {
+        IoStatus::of(self.write(buf.as_slice()), out_written)
+    }
+
+    fn dyn_flush(self: &'_ mut Self) -> IoStatus {
+        IoStatus::of(self.flush(), &mut ())
+    }
+}
+
+/// An FFI-safe `io::SeekFrom` discriminant.
+#[derive_ReprC]
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeekWhence {
+    /// The `offset` is to be non-negative.
+    Start = 0,
+    End = 1,
+    Current = 2,
+}
+
+/// Models an [`io::Seek`].
+#[derive_ReprC(dyn)]
+pub trait FfiSeek {
+    /// On success, the new position (from the start of the stream) is written to `out_position`.
+    fn dyn_seek(
+        self: &'_ mut Self,
+        whence: SeekWhence,
+        offset: i64,
+        out_position: &'_ mut u64,
+    ) -> IoStatus;
+}
+
+impl<T: io::Seek> FfiSeek for T {
+    fn dyn_seek(
+        self: &'_ mut Self,
+        whence: SeekWhence,
+        offset: i64,
+        out_position: &'_ mut u64,
+    ) -> IoStatus {
+        let pos = match whence {
+            | SeekWhence::Start => match u64::try_from(offset) {
+                | Ok(offset) => io::SeekFrom::Start(offset),
+                | Err(_) => return IoStatus::InvalidInput,
+            },
+            | SeekWhence::End => io::SeekFrom::End(offset),
+            | SeekWhence::Current => io::SeekFrom::Current(offset),
+        };
+        IoStatus::of(self.seek(pos), out_position)
+    }
+}
+
+/// Turns an [`FfiRead`], [`FfiWrite`] and/or [`FfiSeek`] implementor, such as a
+/// `VirtualPtr<dyn FfiRead>`, back into an [`io::Read`], [`io::Write`] and/or [`io::Seek`] one.
+#[derive(Debug)]
+pub struct IoAdapter<T>(pub T);
+
+impl<T: FfiRead> io::Read for IoAdapter<T> {
+    fn read(
+        self: &'_ mut Self,
+        buf: &'_ mut [u8],
+    ) -> io::Result<usize> {
+        let len = buf.len();
+        let mut read = 0;
+        self.0.dyn_read(buf.into(), &mut read).into_result()?;
+        if read > len {
+            return Err(io::ErrorKind::InvalidData.into());
+        }
+        Ok(read)
+    }
+}
+
+impl<T: FfiWrite> io::Write for IoAdapter<T> {
+    fn write(
+        self: &'_ mut Self,
+        buf: &'_ [u8],
+    ) -> io::Result<usize> {
+        let mut written = 0;
+        self.0.dyn_write(buf.into(), &mut written).into_result()?;
+        if written > buf.len() {
+            return Err(io::ErrorKind::InvalidData.into());
+        }
+        Ok(written)
+    }
+
+    fn flush(self: &'_ mut Self) -> io::Result<()> {
+        self.0.dyn_flush().into_result()
+    }
+}
+
+impl<T: FfiSeek> io::Seek for IoAdapter<T> {
+    fn seek(
+        self: &'_ mut Self,
+        pos: io::SeekFrom,
+    ) -> io::Result<u64> {
+        let (whence, offset) = match pos {
+            | io::SeekFrom::Start(offset) => (
+                SeekWhence::Start,
+                i64::try_from(offset).map_err(|_| io::ErrorKind::InvalidInput)?,
+            ),
+            | io::SeekFrom::End(offset) => (SeekWhence::End, offset),
+            | io::SeekFrom::Current(offset) => (SeekWhence::Current, offset),
+        };
+        let mut position = 0;
+        self.0.dyn_seek(whence, offset, &mut position).into_result()?;
+        Ok(position)
+    }
+}
+
+#[test]
+fn io_round_trip() {
+    use io::Read as _;
+    use io::Seek as _;
+    use io::Write as _;
+
+    let reader = io::Cursor::new(b"Hello there".to_vec());
+    let mut reader = IoAdapter(VirtualPtr::<dyn FfiRead>::from(Box::new(reader)));
+    let mut hello = [0; 5];
+    reader.read_exact(&mut hello).unwrap();
+    assert_eq!(&hello, b"Hello");
+    assert_eq!(
+        reader.read_exact(&mut [0; 10]).unwrap_err().kind(),
+        io::ErrorKind::UnexpectedEof,
+    );
+
+    let mut buf = vec![];
+    let mut writer = IoAdapter(VirtualPtr::<dyn '_ + FfiWrite>::from(Box::new(&mut buf)));
+    write!(writer, "General {}!", "Kenobi").unwrap();
+    writer.flush().unwrap();
+    drop(writer);
+    assert_eq!(buf, b"General Kenobi!");
+
+    let seeker = io::Cursor::new([0_u8; 8]);
+    let mut seeker = IoAdapter(VirtualPtr::<dyn FfiSeek>::from(Box::new(seeker)));
+    assert_eq!(seeker.seek(io::SeekFrom::End(-2)).unwrap(), 6);
+    assert_eq!(
+        seeker.seek(io::SeekFrom::Current(-7)).unwrap_err().kind(),
+        io::ErrorKind::InvalidInput,
+    );
+
+    /// A misbehaving reader, claiming to have read more than it was given.
+    struct Liar;
+    impl FfiRead for Liar {
+        fn dyn_read(
+            self: &'_ mut Self,
+            _: c_slice::Mut<'_, u8>,
+            out_read: &'_ mut usize,
+        ) -> IoStatus {
+            *out_read = usize::MAX;
+            IoStatus::Ok
+        }
+    }
+    assert_eq!(
+        IoAdapter(Liar).read(&mut [0; 4]).unwrap_err().kind(),
+        io::ErrorKind::InvalidData,
+    );
+}
@@ -0,0 +1,258 @@
+//! Owning pointers to objects allocated (and to be freed) by the foreign side.
+
+use_prelude!();
+
+ReprC! {
+    #[repr(C)]
+    /// An owning pointer to a `T` allocated by the foreign side, along with the
+    /// foreign destructor with which to release it, which it calls when
+    /// dropped.
+    ///
+    /// This gives RAII semantics to foreign resources, much like a
+    /// [`repr_c::Box`] does for Rust-allocated ones:
+    ///
+    /// ```C
+    /// typedef struct ForeignBox_Foo {
+    ///     Foo_t * ptr;
+    ///     void (*drop)(Foo_t *);
+    /// } ForeignBox_Foo_t;
+    /// ```
+    ///
+    /// See also [`ForeignBoxWithCtx`], for destructors taking a context.
+    ///
+    /// # `Send` and `Sync`
+    ///
+    /// A `ForeignBox<T>` is neither `Send` nor `Sync` by default, since that
+    /// depends on the foreign code: see [`ForeignSend`] and [`ForeignSync`]
+    /// to opt into it.
+    pub
+    struct ForeignBox[T]
+    where {
+        T : ReprC,
+    }
+    {
+        ptr: ptr::NonNull<T>,
+        drop: unsafe extern "C" fn(*mut T),
+    }
+}
+
+impl<T: ReprC> ForeignBox<T> {
+    /// # Safety
+    ///
+    ///   - `ptr` must point to a valid `T`, owned by the returned
+    ///     `ForeignBox`;
+    ///
+    ///   - `drop` must be sound to call (once) on it.
+    #[inline]
+    pub unsafe fn from_raw(
+        ptr: ptr::NonNull<T>,
+        drop: unsafe extern "C" fn(*mut T),
+    ) -> ForeignBox<T> {
+        Self { ptr, drop }
+    }
+
+    #[inline]
+    pub fn as_ptr(self: &'_ ForeignBox<T>) -> *mut T {
+        self.ptr.as_ptr()
+    }
+
+    /// Releases the ownership of the pointee, without calling the
+    /// destructor, which is returned alongside the pointer.
+    #[inline]
+    pub fn into_raw(self: ForeignBox<T>) -> (ptr::NonNull<T>, unsafe extern "C" fn(*mut T)) {
+        let this = mem::ManuallyDrop::new(self);
+        (this.ptr, this.drop)
+    }
+}
+
+impl<T: ReprC> Drop for ForeignBox<T> {
+    #[inline]
+    fn drop(self: &'_ mut ForeignBox<T>) {
+        unsafe { (self.drop)(self.ptr.as_ptr()) }
+    }
+}
+
+impl<T: ReprC> Deref for ForeignBox<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(self: &'_ ForeignBox<T>) -> &'_ T {
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl<T: ReprC> DerefMut for ForeignBox<T> {
+    #[inline]
+    fn deref_mut(self: &'_ mut ForeignBox<T>) -> &'_ mut T {
+        unsafe { self.ptr.as_mut() }
+    }
+}
+
+unsafe impl<T: ForeignSend> Send for ForeignBox<T> {}
+
+unsafe impl<T: ForeignSync> Sync for ForeignBox<T> {}
+
+impl<T: ReprC + fmt::Debug> fmt::Debug for ForeignBox<T> {
+    fn fmt(
+        self: &'_ Self,
+        fmt: &'_ mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        T::fmt(self, fmt)
+    }
+}
+
+/// Opt-in marker for [`ForeignBox<Self>`] and [`ForeignBoxWithCtx<Self>`]
+/// to be [`Send`].
+///
+/// # Safety
+///
+/// The foreign side must allow a `Self` object to be used, and then
+/// destroyed (context included), from any thread, _i.e._, these objects must
+/// not rely on thread-local state.
+pub unsafe trait ForeignSend: ReprC {}
+
+/// Opt-in marker for [`ForeignBox<Self>`] and [`ForeignBoxWithCtx<Self>`]
+/// to be [`Sync`].
+///
+/// # Safety
+///
+/// The foreign side must allow a `Self` object to be accessed, through
+/// shared references, from several threads concurrently.
+pub unsafe trait ForeignSync: ReprC {}
+
+ReprC! {
+    #[repr(C)]
+    /// Same as a [`ForeignBox`], but for the foreign destructor taking an
+    /// extra (owned) context, such as the allocator which the object comes
+    /// from:
+    ///
+    /// ```C
+    /// typedef struct ForeignBoxWithCtx_Foo {
+    ///     Foo_t * ptr;
+    ///     void * ctx;
+    ///     void (*drop)(void *, Foo_t *);
+    /// } ForeignBoxWithCtx_Foo_t;
+    /// ```
+    pub
+    struct ForeignBoxWithCtx[T]
+    where {
+        T : ReprC,
+    }
+    {
+        ptr: ptr::NonNull<T>,
+        ctx: *mut c_void,
+        drop: unsafe extern "C" fn(*mut c_void, *mut T),
+    }
+}
+
+impl<T: ReprC> ForeignBoxWithCtx<T> {
+    /// # Safety
+    ///
+    ///   - `ptr` must point to a valid `T`, owned by the returned
+    ///     `ForeignBoxWithCtx`, as is `ctx`;
+    ///
+    ///   - `drop` must be sound to call (once) on them.
+    #[inline]
+    pub unsafe fn from_raw(
+        ptr: ptr::NonNull<T>,
+        ctx: *mut c_void,
+        drop: unsafe extern "C" fn(*mut c_void, *mut T),
+    ) -> ForeignBoxWithCtx<T> {
+        Self { ptr, ctx, drop }
+    }
+
+    #[inline]
+    pub fn as_ptr(self: &'_ ForeignBoxWithCtx<T>) -> *mut T {
+        self.ptr.as_ptr()
+    }
+
+    /// Releases the ownership of the pointee and of the context, without
+    /// calling the destructor, which is returned alongside them.
+    #[inline]
+    pub fn into_raw(
+        self: ForeignBoxWithCtx<T>,
+    ) -> (
+        ptr::NonNull<T>,
+        *mut c_void,
+        unsafe extern "C" fn(*mut c_void, *mut T),
+    ) {
+        let this = mem::ManuallyDrop::new(self);
+        (this.ptr, this.ctx, this.drop)
+    }
+}
+
+impl<T: ReprC> Drop for ForeignBoxWithCtx<T> {
+    #[inline]
+    fn drop(self: &'_ mut ForeignBoxWithCtx<T>) {
+        unsafe { (self.drop)(self.ctx, self.ptr.as_ptr()) }
+    }
+}
+
+impl<T: ReprC> Deref for ForeignBoxWithCtx<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(self: &'_ ForeignBoxWithCtx<T>) -> &'_ T {
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl<T: ReprC> DerefMut for ForeignBoxWithCtx<T> {
+    #[inline]
+    fn deref_mut(self: &'_ mut ForeignBoxWithCtx<T>) -> &'_ mut T {
+        unsafe { self.ptr.as_mut() }
+    }
+}
+
+unsafe impl<T: ForeignSend> Send for ForeignBoxWithCtx<T> {}
+
+unsafe impl<T: ForeignSync> Sync for ForeignBoxWithCtx<T> {}
+
+impl<T: ReprC + fmt::Debug> fmt::Debug for ForeignBoxWithCtx<T> {
+    fn fmt(
+        self: &'_ Self,
+        fmt: &'_ mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        T::fmt(self, fmt)
+    }
+}
+
+#[test]
+fn foreign_box() {
+    use ::core::sync::atomic::AtomicU32;
+    use ::core::sync::atomic::Ordering;
+
+    static DROPPED: AtomicU32 = AtomicU32::new(0);
+
+    unsafe extern "C" fn foreign_drop(ptr: *mut u32) {
+        DROPPED.fetch_add(unsafe { *ptr }, Ordering::Relaxed);
+    }
+
+    let mut storage = 27_u32;
+    let mut foreign = unsafe {
+        ForeignBox::from_raw(ptr::NonNull::from(&mut storage), foreign_drop)
+    };
+    *foreign += 15;
+    assert_eq!(*foreign, 42);
+    drop(foreign);
+    assert_eq!(DROPPED.load(Ordering::Relaxed), 42);
+
+    unsafe extern "C" fn foreign_drop_with_ctx(
+        ctx: *mut c_void,
+        ptr: *mut u32,
+    ) {
+        DROPPED.fetch_add(unsafe { *ptr * *ctx.cast::<u32>() }, Ordering::Relaxed);
+    }
+
+    let mut factor = 2_u32;
+    let foreign = unsafe {
+        ForeignBoxWithCtx::from_raw(
+            ptr::NonNull::from(&mut storage),
+            (&raw mut factor).cast(),
+            foreign_drop_with_ctx,
+        )
+    };
+    assert_eq!(*foreign, 42);
+    drop(foreign);
+    assert_eq!(DROPPED.load(Ordering::Relaxed), 42 + 2 * 42);
+}
@@ -578,6 +578,7 @@ hidden_export! {
     }
 }
 
+use self::languages::Deallocator;
 use self::languages::FunctionArg;
 use self::languages::HeaderLanguage;
 use self::languages::PhantomCType;
@@ -592,5 +593,19 @@ fn __define_fn__(
     ret_ty: &'_ dyn PhantomCType,
 ) -> io::Result<()> {
     let dyn_lang: &dyn HeaderLanguage = lang.as_dyn();
+    let note;
+    let docs_with_note;
+    let docs = match ret_ty.metadata().dyn_request::<Deallocator>() {
+        | Some(Deallocator(deallocator)) => {
+            note = format!("The returned value is to be freed with {deallocator}.");
+            docs_with_note = if docs.is_empty() {
+                vec![&*note]
+            } else {
+                docs.iter().copied().chain(["", &*note]).collect()
+            };
+            &docs_with_note[..]
+        },
+        | None => docs,
+    };
     dyn_lang.declare_function(dyn_lang, definer, docs, fname, args, ret_ty)
 }
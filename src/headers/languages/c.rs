@@ -2,6 +2,14 @@ use super::*;
 
 pub struct C;
 
+/// Metadata, [provided][crate::headers::provider] by the `CType`s of values
+/// which C code is expected to free on its own, naming the function (or
+/// expression) with which to do so.
+///
+/// It is mentioned in the docs of the functions returning such values, for
+/// every [`HeaderLanguage`].
+pub struct Deallocator(pub &'static str);
+
 impl HeaderLanguage for C {
     fn emit_docs(
         self: &'_ Self,
@@ -90,11 +98,7 @@ impl HeaderLanguage for C {
         if let _ = indent.scope() {
             for v in variants {
                 this.emit_docs(ctx, v.docs, indent)?;
-                let variant_name = crate::utils::screaming_case(short_name, v.name) /* ctx.adjust_variant_name(
-                    Language::C,
-                    enum_name,
-                    v.name,
-                ) */;
+                let variant_name = v.global_name(short_name);
                 if let Some(value) = v.discriminant {
                     out!(("{variant_name} = {value:?},"));
                 } else {
@@ -165,6 +169,48 @@ impl HeaderLanguage for C {
         Ok(())
     }
 
+    fn declare_union(
+        self: &'_ Self,
+        this: &dyn HeaderLanguage,
+        ctx: &'_ mut dyn Definer,
+        docs: Docs<'_>,
+        self_ty: &'_ dyn PhantomCType,
+        fields: &'_ [StructField<'_>],
+    ) -> io::Result<()> {
+        let ref indent = Indentation::new(4 /* ctx.indent_width() */);
+        mk_out!(indent, ctx.out());
+        let short_name = self_ty.short_name();
+        let full_ty_name = self_ty.name(this);
+
+        if self_ty.size() == 0 {
+            panic!("C does not support zero-sized unions!")
+        }
+
+        this.emit_docs(ctx, docs, indent)?;
+        out!(("typedef union {short_name} {{"));
+        if let _ = indent.scope() {
+            let ref mut first = true;
+            for &StructField { docs, name, ty } in fields {
+                // Skip ZSTs
+                if ty.size() == 0 {
+                    continue;
+                }
+                if mem::take(first).not() {
+                    out!("\n");
+                }
+                this.emit_docs(ctx, docs, indent)?;
+                out!(
+                    ("{};"),
+                    ty.name_wrapping_var(this, Some(&name))
+                );
+            }
+        }
+        out!(("}} {full_ty_name};"));
+
+        out!("\n");
+        Ok(())
+    }
+
     fn declare_opaque_type(
         self: &'_ Self,
         this: &dyn HeaderLanguage,
@@ -305,6 +351,9 @@ impl HeaderLanguage for C {
             | Primitive::CChar => {
                 write!(out, "char")?;
             },
+            | Primitive::Char16 => {
+                write!(out, "char16_t")?;
+            },
             | Primitive::Integer { signed, bitwidth } => match bitwidth {
                 | IntBitWidth::PointerSized => {
                     let sign_prefix = if signed { "s" } else { "" };
@@ -343,6 +392,46 @@ impl HeaderLanguage for C {
         )
     }
 
+    fn define_atomic_ty(
+        self: &'_ Self,
+        _this: &dyn HeaderLanguage,
+        definer: &'_ mut dyn Definer,
+    ) -> io::Result<()> {
+        // `_Atomic(T)` is not valid C++ (before C++23): C++ consumers get the
+        // (same-layout, on every supported ABI) `std::atomic<T>` instead.
+        // Since we are within an `extern "C"` block, `<atomic>` has to be
+        // included from within an `extern "C++"` one.
+        definer.define_once("SAFER_FFI_ATOMIC", &mut |definer| {
+            write!(definer.out(), concat! {
+                "\n",
+                "#ifndef SAFER_FFI_ATOMIC\n",
+                "#ifdef __cplusplus\n",
+                "extern \"C++\" {\n",
+                "#include <atomic>\n",
+                "}\n",
+                "#define SAFER_FFI_ATOMIC(T) std::atomic<T>\n",
+                "#else\n",
+                "#define SAFER_FFI_ATOMIC(T) _Atomic(T)\n",
+                "#endif\n",
+                "#endif\n",
+                "\n",
+            },)
+        })
+    }
+
+    fn emit_atomic_ty(
+        self: &'_ Self,
+        this: &dyn HeaderLanguage,
+        out: &mut dyn io::Write,
+        value_ty: &'_ dyn PhantomCType,
+    ) -> io::Result<()> {
+        write!(
+            out,
+            "SAFER_FFI_ATOMIC({value_ty})",
+            value_ty = F(|out| value_ty.render(out, this)),
+        )
+    }
+
     fn define_array_ty(
         self: &'_ Self,
         this: &dyn HeaderLanguage,
@@ -417,6 +506,30 @@ impl HeaderLanguage for C {
                     },)
                 })?;
             },
+            | Primitive::Char16 => {
+                // `char16_t` is a keyword in C++, and older macOS SDKs lack `<uchar.h>`.
+                definer.define_once("char16_t", &mut |definer| {
+                    write!(definer.out(), concat! {
+                        "\n",
+                        "#ifndef __cplusplus\n",
+                        "#if defined(__has_include)\n",
+                        "#if __has_include(<uchar.h>)\n",
+                        "#include <uchar.h>\n",
+                        "#else\n",
+                        "#include <stdint.h>\n",
+                        "typedef uint_least16_t char16_t;\n",
+                        "#endif\n",
+                        "#elif defined(__APPLE__)\n",
+                        "#include <stdint.h>\n",
+                        "typedef uint_least16_t char16_t;\n",
+                        "#else\n",
+                        "#include <uchar.h>\n",
+                        "#endif\n",
+                        "#endif\n",
+                        "\n",
+                    },)
+                })?;
+            },
             | _ => {},
         }
         Ok(())
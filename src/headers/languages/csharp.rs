@@ -33,6 +33,9 @@ impl HeaderLanguage for CSharp {
             | Primitive::CChar => {
                 write!(out, "byte")?;
             },
+            | Primitive::Char16 => {
+                write!(out, "char")?;
+            },
             | Primitive::Integer { signed, bitwidth } => match bitwidth {
                 | IntBitWidth::PointerSized => {
                     let sign_prefix = if signed { "" } else { "U" };
@@ -233,6 +236,63 @@ impl HeaderLanguage for CSharp {
         Ok(())
     }
 
+    fn declare_union(
+        self: &'_ Self,
+        this: &dyn HeaderLanguage,
+        ctx: &'_ mut dyn Definer,
+        docs: Docs<'_>,
+        self_ty: &'_ dyn PhantomCType,
+        fields: &'_ [StructField<'_>],
+    ) -> io::Result<()> {
+        let ref indent = Indentation::new(4 /* ctx.indent_width() */);
+        mk_out!(indent, ctx.out());
+
+        let size = self_ty.size();
+        if size == 0 {
+            panic!("C# does not support zero-sized unions!")
+        }
+
+        let ref name = self_ty.name(this);
+
+        this.emit_docs(ctx, docs, indent)?;
+        out!((
+            "[StructLayout(LayoutKind.Explicit, Size = {size})]"
+            "public unsafe struct {name} {{"
+        ));
+        if let _ = indent.scope() {
+            let ref mut first = true;
+            for &StructField {
+                docs,
+                name,
+                ty: field_ty,
+            } in fields
+            {
+                // Skip ZSTs
+                if field_ty.size() == 0 {
+                    continue;
+                }
+                if mem::take(first).not() {
+                    out!("\n");
+                }
+                this.emit_docs(ctx, docs, indent)?;
+                out!(("[FieldOffset(0)]"));
+                if let Some(CSharpMarshaler(csharp_marshaler)) = field_ty.metadata().dyn_request() {
+                    out!((
+                        "[MarshalAs({csharp_marshaler})]"
+                    ));
+                }
+                out!(
+                    ("public {};"),
+                    F(|out| field_ty.render_wrapping_var(out, this, Some(&name))),
+                );
+            }
+        }
+        out!(("}}"));
+
+        out!("\n");
+        Ok(())
+    }
+
     fn declare_opaque_type(
         self: &'_ Self,
         this: &dyn HeaderLanguage,
@@ -55,11 +55,7 @@ impl HeaderLanguage for Lua {
         if let _ = indent.scope() {
             for v in variants {
                 this.emit_docs(ctx, v.docs, indent)?;
-                let variant_name = crate::utils::screaming_case(short_name, v.name) /* ctx.adjust_variant_name(
-                    Language::C,
-                    enum_name,
-                    v.name,
-                ) */;
+                let variant_name = v.global_name(short_name);
                 if let Some(value) = v.discriminant {
                     out!(("{variant_name} = {value:?},"));
                 } else {
@@ -90,6 +86,17 @@ impl HeaderLanguage for Lua {
         C.declare_struct(this, ctx, docs, self_ty, fields)
     }
 
+    fn declare_union(
+        self: &'_ Self,
+        this: &dyn HeaderLanguage,
+        ctx: &'_ mut dyn Definer,
+        docs: Docs<'_>,
+        self_ty: &'_ dyn PhantomCType,
+        fields: &'_ [StructField<'_>],
+    ) -> io::Result<()> {
+        C.declare_union(this, ctx, docs, self_ty, fields)
+    }
+
     fn declare_opaque_type(
         self: &'_ Self,
         this: &dyn HeaderLanguage,
@@ -160,7 +167,11 @@ impl HeaderLanguage for Lua {
         out: &mut dyn io::Write,
         primitive: Primitive,
     ) -> io::Result<()> {
-        C.emit_primitive_ty(out, primitive)
+        match primitive {
+            // LuaJIT's `ffi.cdef` does not know of `char16_t`.
+            | Primitive::Char16 => write!(out, "uint16_t"),
+            | _ => C.emit_primitive_ty(out, primitive),
+        }
     }
 
     fn emit_pointer_ty(
@@ -14,6 +14,7 @@ use crate::utils::DisplayFromFn as F;
 pub mod primitives;
 
 pub use c::C;
+pub use c::Deallocator;
 mod c;
 
 pub use csharp::CSharp;
@@ -95,6 +96,23 @@ pub trait HeaderLanguage: UpcastAny {
         fields: &'_ [StructField<'_>],
     ) -> io::Result<()>;
 
+    /// All the `fields` start at offset `0`, as with a C `union`.
+    fn declare_union(
+        self: &'_ Self,
+        // subrecursing language
+        _this: &dyn HeaderLanguage,
+        _ctx: &'_ mut dyn Definer,
+        _docs: Docs<'_>,
+        self_ty: &'_ dyn PhantomCType,
+        _fields: &'_ [StructField<'_>],
+    ) -> io::Result<()> {
+        Err(io::Error::other(format!(
+            "`{}` does not support `union`s, such as `{}`",
+            self.language_name(),
+            self_ty.short_name(),
+        )))
+    }
+
     fn declare_opaque_type(
         self: &'_ Self,
         // subrecursing language
@@ -140,6 +158,30 @@ pub trait HeaderLanguage: UpcastAny {
         pointee: &'_ dyn PhantomCType,
     ) -> io::Result<()>;
 
+    /// Setup needed by [`Self::emit_atomic_ty()`], if any.
+    fn define_atomic_ty(
+        self: &'_ Self,
+        // subrecursing language
+        _this: &dyn HeaderLanguage,
+        _ctx: &'_ mut dyn Definer,
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// The atomic version of `value_ty` (same layout).
+    ///
+    /// By default, for languages lacking C11 `_Atomic` types, this is the
+    /// plain `value_ty`, with an inline comment documenting its atomicity.
+    fn emit_atomic_ty(
+        self: &'_ Self,
+        this: &dyn HeaderLanguage,
+        out: &mut dyn io::Write,
+        value_ty: &'_ dyn PhantomCType,
+    ) -> io::Result<()> {
+        write!(out, "/* atomic */ ")?;
+        value_ty.render(out, this)
+    }
+
     fn emit_void_output_type(
         self: &'_ Self,
         _out: &mut dyn io::Write,
@@ -239,6 +281,50 @@ pub struct EnumVariant<'lt> {
     pub name: &'lt str,
 
     pub discriminant: Option<&'lt dyn ::core::fmt::Debug>,
+
+    /// How `name` ought to be spelled in languages lacking scoped `enum`s.
+    pub naming: VariantNaming,
+}
+
+impl EnumVariant<'_> {
+    /// The name of the variant in languages where `enum` variants live in
+    /// the global namespace (_e.g._, C).
+    pub fn global_name(
+        self: &'_ Self,
+        enum_short_name: &'_ str,
+    ) -> String {
+        match self.naming {
+            | VariantNaming::ScreamingPrefixed => {
+                crate::utils::screaming_case(enum_short_name, self.name).to_string()
+            },
+            | VariantNaming::Screaming => crate::utils::screaming_case("", self.name).to_string(),
+            | VariantNaming::Prefixed => format!("{enum_short_name}_{}", self.name),
+            | VariantNaming::Verbatim => self.name.to_owned(),
+        }
+    }
+}
+
+/// Naming style of `enum` variants, as selected by
+/// `#[derive_ReprC(variant_naming = "…")]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum VariantNaming {
+    /// `"screaming_prefixed"`: `MyEnum::SomeVariant` becomes
+    /// `MY_ENUM_SOME_VARIANT` (the default).
+    #[default]
+    ScreamingPrefixed,
+
+    /// `"screaming"`: `MyEnum::SomeVariant` becomes `SOME_VARIANT`.
+    Screaming,
+
+    /// `"prefixed"`: `MyEnum::SomeVariant` becomes `MyEnum_SomeVariant`.
+    Prefixed,
+
+    /// `"verbatim"`: `MyEnum::SomeVariant` becomes `SomeVariant`.
+    ///
+    /// This is also the style used for variants with a field-level
+    /// `#[ffi(rename = "…")]`.
+    Verbatim,
 }
 
 pub struct StructField<'lt> {
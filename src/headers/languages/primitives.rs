@@ -58,6 +58,8 @@ pub enum FloatBitWidth {
 pub enum Primitive {
     Bool,
     CChar,
+    /// A UTF-16 code unit: `char16_t` in C.
+    Char16,
     Integer { signed: bool, bitwidth: IntBitWidth },
     Float { bitwidth: FloatBitWidth },
 }
@@ -34,11 +34,7 @@ impl HeaderLanguage for Python {
         if let _ = indent.scope() {
             for v in variants {
                 this.emit_docs(ctx, v.docs, indent)?;
-                let variant_name = crate::utils::screaming_case(short_name, v.name) /* ctx.adjust_variant_name(
-                    Language::C,
-                    enum_name,
-                    v.name,
-                ) */;
+                let variant_name = v.global_name(short_name);
                 out!(("{variant_name},"));
             }
         }
@@ -59,6 +55,17 @@ impl HeaderLanguage for Python {
         C.declare_struct(this, ctx, docs, self_ty, fields)
     }
 
+    fn declare_union(
+        self: &'_ Self,
+        this: &dyn HeaderLanguage,
+        ctx: &'_ mut dyn Definer,
+        docs: Docs<'_>,
+        self_ty: &'_ dyn PhantomCType,
+        fields: &'_ [StructField<'_>],
+    ) -> io::Result<()> {
+        C.declare_union(this, ctx, docs, self_ty, fields)
+    }
+
     fn declare_opaque_type(
         self: &'_ Self,
         this: &dyn HeaderLanguage,
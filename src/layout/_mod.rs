@@ -335,6 +335,17 @@ pub unsafe trait CType: Sized + Copy {
         F(|out| Self::render_wrapping_var(out, language, var_name)).to_string()
     }
 
+    #[apply(__cfg_alloc__!)]
+    /// Describes the C layout of the type, for runtime introspection.
+    ///
+    /// The default implementation does not expose anything beyond the size and
+    /// alignment of the type, describing it as [`TypeKind::Opaque`].
+    ///
+    /// See [`describe()`].
+    fn describe() -> TypeDescriptor {
+        TypeDescriptor::new::<Self>(TypeKind::Opaque)
+    }
+
     #[apply(__cfg_headers__!)]
     /// Optional language-specific metadata attached to the type (_e.g._,
     /// some `[MarshalAs(UnmanagedType.FunctionPtr)]` annotation for C#).
@@ -539,7 +550,25 @@ pub unsafe trait ReprC: Sized {
     ///
     /// Still, there may be _safety_ invariants involved with custom types,
     /// so even then it is unclear.
+    ///
+    /// ## No dereferencing
+    ///
+    /// Since this (safe) function may be fed arbitrary bit-patterns, it must
+    /// not dereference the pointers therein (_e.g._, to check that a C string
+    /// is valid UTF-8): such checks belong to explicit, checked, constructors
+    /// or accessors.
     fn is_valid(it: &'_ Self::CLayout) -> bool;
+
+    /// Rewrites a bit-pattern accepted by [`Self::is_valid()`] into the one
+    /// of the corresponding Rust value, for the few types for which these
+    /// differ (_e.g._, a C `(NULL, 0)` slice, which maps to a Rust slice with
+    /// a dangling pointer); run by [`from_raw`] right before transmuting.
+    ///
+    /// Types wrapping other `ReprC` types, and delegating to their
+    /// `is_valid()`, are to delegate to this function as well.
+    #[doc(hidden)]
+    #[inline]
+    fn __canonicalize__(_it: &'_ mut Self::CLayout) {}
 }
 
 pub type CLayoutOf<ImplReprC> = <ImplReprC as ReprC>::CLayout;
@@ -589,10 +618,11 @@ pub unsafe fn from_raw_unchecked<T: ReprC>(c_layout: T::CLayout) -> T {
 
 #[deny(unsafe_op_in_unsafe_fn)]
 #[inline]
-pub unsafe fn from_raw<T: ReprC>(c_layout: T::CLayout) -> Option<T> {
+pub unsafe fn from_raw<T: ReprC>(mut c_layout: T::CLayout) -> Option<T> {
     if <T as ReprC>::is_valid(&c_layout).not() {
         None
     } else {
+        <T as ReprC>::__canonicalize__(&mut c_layout);
         Some(unsafe {
             const_assert! {
                 for [T]
@@ -609,6 +639,11 @@ pub unsafe fn into_raw<T: ReprC>(it: T) -> T::CLayout {
     unsafe { crate::utils::transmute_unchecked(::core::mem::ManuallyDrop::new(it)) }
 }
 
+#[apply(cfg_alloc)]
+pub use descriptor::*;
+#[apply(cfg_alloc)]
+mod descriptor;
+
 pub use impls::Opaque;
 pub(crate) mod impls;
 
@@ -0,0 +1,139 @@
+//! Runtime introspection of the C layout of [`ReprC`] types.
+//!
+//! See [`describe()`].
+
+use super::*;
+
+/// Describes the C layout of `T`, _i.e._, that of its [`ReprC::CLayout`].
+///
+/// Contrary to the header-generation machinery, this is available without the
+/// `headers` feature, so as to be usable by debuggers, serializers, or ABI
+/// checks.
+///
+/// ```rust
+/// use ::safer_ffi::layout::{self, TypeKind};
+/// use ::safer_ffi::prelude::*;
+///
+/// #[derive_ReprC]
+/// #[repr(C)]
+/// struct Point {
+///     x: u8,
+///     y: u32,
+/// }
+///
+/// let descriptor = layout::describe::<Point>();
+/// assert_eq!(descriptor.size, 8);
+/// let TypeKind::Struct { fields } = descriptor.kind else { unreachable!() };
+/// assert_eq!(fields[1].name, "y");
+/// assert_eq!(fields[1].offset, 4);
+/// ```
+pub fn describe<T: ReprC>() -> TypeDescriptor {
+    <T::CLayout as CType>::describe()
+}
+
+/// The description of a [`CType`], as returned by [`describe()`] or
+/// [`CType::describe()`].
+#[derive(Debug, Clone)]
+pub struct TypeDescriptor {
+    pub kind: TypeKind,
+
+    /// The C name of the type when the `headers` feature is enabled, and
+    /// the Rust [`type_name`][::core::any::type_name] of the [`CType`]
+    /// otherwise.
+    pub name: rust::String,
+
+    pub size: usize,
+
+    pub align: usize,
+}
+
+impl TypeDescriptor {
+    /// Describes `T` with the given `kind`, filling in the other fields.
+    pub fn new<T: CType>(kind: TypeKind) -> TypeDescriptor {
+        TypeDescriptor {
+            kind,
+            name: name_of::<T>(),
+            size: ::core::mem::size_of::<T>(),
+            align: ::core::mem::align_of::<T>(),
+        }
+    }
+}
+
+#[cfg(feature = "headers")]
+fn name_of<T: CType>() -> rust::String {
+    T::name(&crate::headers::languages::C)
+}
+
+#[cfg(not(feature = "headers"))]
+fn name_of<T: CType>() -> rust::String {
+    ::core::any::type_name::<T>().into()
+}
+
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum TypeKind {
+    Bool,
+
+    CChar,
+
+    /// A UTF-16 code unit.
+    Char16,
+
+    Integer {
+        signed: bool,
+    },
+
+    Float,
+
+    /// Zero-sized type, such as `void` return types.
+    Void,
+
+    Pointer {
+        mutable: bool,
+        /// Lazily computed, since pointers may be self-referential.
+        pointee: fn() -> TypeDescriptor,
+    },
+
+    FunctionPointer,
+
+    Array {
+        item: rust::Box<TypeDescriptor>,
+        len: usize,
+    },
+
+    Struct {
+        fields: rust::Vec<FieldDescriptor>,
+    },
+
+    /// All the fields are at offset `0`.
+    Union {
+        fields: rust::Vec<FieldDescriptor>,
+    },
+
+    Enum {
+        variants: rust::Vec<VariantDescriptor>,
+    },
+
+    /// Types whose layout is not exposed, such as `repr(opaque)` ones.
+    ///
+    /// This is also the default for `CType`s which do not describe their
+    /// layout.
+    Opaque,
+}
+
+#[derive(Debug, Clone)]
+pub struct FieldDescriptor {
+    pub name: &'static str,
+
+    /// Offset, in bytes, of the field within its `struct`.
+    pub offset: usize,
+
+    pub ty: TypeDescriptor,
+}
+
+#[derive(Debug, Clone)]
+pub struct VariantDescriptor {
+    pub name: &'static str,
+
+    pub discriminant: i128,
+}
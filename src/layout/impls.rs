@@ -141,6 +141,13 @@ const _: () = {
             for Option<unsafe extern "C" fn ($($An, $($Ai ,)*)?) -> Ret>
         {
             type OPAQUE_KIND = OpaqueKind::Concrete;
+
+            __cfg_alloc__! {
+                fn describe() -> TypeDescriptor {
+                    TypeDescriptor::new::<Self>(TypeKind::FunctionPointer)
+                }
+            }
+
             __cfg_headers__! {
                 fn short_name() -> String {
                     // ret_arg1_arg2_fptr
@@ -331,6 +338,17 @@ const _: () = {
             for $RustInt
         {
             type OPAQUE_KIND = OpaqueKind::Concrete;
+
+            __cfg_alloc__! {
+                fn describe() -> TypeDescriptor {
+                    TypeDescriptor::new::<Self>(TypeKind::Integer {
+                        signed: { #[allow(unused_comparisons)] {
+                            Self::MIN < 0
+                        }},
+                    })
+                }
+            }
+
             __cfg_headers__! {
                 fn short_name () -> String {
                     $CInt.into()
@@ -404,6 +422,13 @@ const _: () = {
             for $fN
         {
             type OPAQUE_KIND = OpaqueKind::Concrete;
+
+            __cfg_alloc__! {
+                fn describe() -> TypeDescriptor {
+                    TypeDescriptor::new::<Self>(TypeKind::Float)
+                }
+            }
+
             __cfg_headers__! {
                 fn short_name () -> String {
                     $Cty.into()
@@ -448,6 +473,15 @@ const _: () = {
         {
             type OPAQUE_KIND = OpaqueKind::Concrete;
 
+            __cfg_alloc__! {
+                fn describe() -> TypeDescriptor {
+                    TypeDescriptor::new::<Self>(TypeKind::Pointer {
+                        mutable: false,
+                        pointee: T::describe,
+                    })
+                }
+            }
+
             __cfg_headers__! {
                 fn short_name () -> String {
                     format!("{}_const_ptr", T::short_name())
@@ -498,6 +532,15 @@ const _: () = {
         {
             type OPAQUE_KIND = OpaqueKind::Concrete;
 
+            __cfg_alloc__! {
+                fn describe() -> TypeDescriptor {
+                    TypeDescriptor::new::<Self>(TypeKind::Pointer {
+                        mutable: true,
+                        pointee: T::describe,
+                    })
+                }
+            }
+
             __cfg_headers__! {
                 fn short_name () -> String {
                     format!("{}_ptr", T::short_name())
@@ -656,6 +699,11 @@ const _: () = {
 
 unsafe impl CType for Bool {
     type OPAQUE_KIND = OpaqueKind::Concrete;
+    __cfg_alloc__! {
+        fn describe() -> TypeDescriptor {
+            TypeDescriptor::new::<Self>(TypeKind::Bool)
+        }
+    }
     __cfg_headers__! {
         fn short_name() -> String {
             "bool".into()
@@ -726,6 +774,11 @@ impl ::core::fmt::Debug for c_int {
 
 unsafe impl CType for c_int {
     type OPAQUE_KIND = OpaqueKind::Concrete;
+    __cfg_alloc__! {
+        fn describe() -> TypeDescriptor {
+            TypeDescriptor::new::<Self>(TypeKind::Integer { signed: true })
+        }
+    }
     __cfg_headers__! {
         fn short_name() -> String {
             "int".into()
@@ -765,11 +818,97 @@ unsafe impl CType for c_int {
     }
 }
 
+/// The `CLayout` of the [`::core::sync::atomic`] types: that of their value
+/// type `T`, but rendered as an `_Atomic(T)` in C headers (through a
+/// `SAFER_FFI_ATOMIC(T)` macro, which is a `std::atomic<T>` in C++), and as a
+/// plain `T` in languages lacking such types.
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+#[allow(missing_debug_implementations)]
+pub struct AtomicLayout<T>(T);
+
+unsafe impl<T: CType> CType for AtomicLayout<T> {
+    type OPAQUE_KIND = OpaqueKind::Concrete;
+    __cfg_alloc__! {
+        fn describe() -> TypeDescriptor {
+            T::describe()
+        }
+    }
+    __cfg_headers__! {
+        fn short_name() -> String {
+            format!("atomic_{}", T::short_name())
+        }
+
+        fn define_self__impl(
+            _language: &'_ dyn HeaderLanguage,
+            _definer: &'_ mut dyn Definer,
+        ) -> io::Result<()>
+        {
+            unimplemented!("directly did `define_self()`");
+        }
+
+        fn define_self(
+            language: &'_ dyn HeaderLanguage,
+            definer: &'_ mut dyn Definer,
+        ) -> io::Result<()>
+        {
+            T::define_self(language, definer)?;
+            language.define_atomic_ty(language, definer)
+        }
+
+        fn render(
+            out: &'_ mut dyn io::Write,
+            language: &'_ dyn HeaderLanguage,
+        ) -> io::Result<()>
+        {
+            language.emit_atomic_ty(language, out, &PhantomData::<T>)
+        }
+
+        fn metadata() -> &'static dyn Provider {
+            T::metadata()
+        }
+    }
+}
+from_CType_impl_ReprC! { @for[T: CType] AtomicLayout<T> }
+
+/* The atomics are `Sync` on the Rust side, and so are the structs containing
+ * them; the C side is to only access them through `<stdatomic.h>` operations. */
+impl_ReprC_for! { unsafe {
+    ::core::sync::atomic::AtomicBool
+        => |ref it: AtomicLayout<Bool>| (it.0.0 & !0b1) == 0
+    ,
+    ::core::sync::atomic::AtomicU32
+        => |ref _it: AtomicLayout<u32>| true
+    ,
+    ::core::sync::atomic::AtomicUsize
+        => |ref _it: AtomicLayout<usize>| true
+    ,
+    @for[T : ReprC]
+    ::core::sync::atomic::AtomicPtr<T>
+        => |ref it: AtomicLayout<*mut T::CLayout>| {
+            (it.0 as usize) % ::core::mem::align_of::<T>() == 0
+        }
+    ,
+}}
+
+/* On some 32-bit targets, `u64` is less aligned than `AtomicU64`. */
+#[cfg(all(target_has_atomic = "64", target_pointer_width = "64"))]
+impl_ReprC_for! { unsafe {
+    ::core::sync::atomic::AtomicU64
+        => |ref _it: AtomicLayout<u64>| true
+    ,
+}}
+
 impl_ReprC_for! { unsafe {
     bool
         => |ref byte: Bool| (byte.0 & !0b1) == 0
     ,
 
+    /* `Ordering` is a `#[repr(i8)]` enum of `-1`, `0`, and `1`. */
+    ::core::cmp::Ordering
+        => |ref it: i8| matches!(*it, -1 ..= 1)
+    ,
+
     @for[T : ReprC]
     ptr::NonNull<T>
         => |ref it: *mut T::CLayout| {
@@ -978,6 +1117,14 @@ use opaque_impls;
 /// Arrays of const size `N`
 unsafe impl<Item: CType, const N: usize> CType for [Item; N] {
     type OPAQUE_KIND = OpaqueKind::Concrete;
+    __cfg_alloc__! {
+        fn describe() -> TypeDescriptor {
+            TypeDescriptor::new::<Self>(TypeKind::Array {
+                item: rust::Box::new(Item::describe()),
+                len: N,
+            })
+        }
+    }
     __cfg_headers__! {
         fn short_name() -> String {
             // item_N_array
@@ -1033,4 +1180,22 @@ unsafe impl<Item: ReprC, const N: usize> ReprC for [Item; N] {
     fn is_valid(it: &'_ Self::CLayout) -> bool {
         it.iter().all(Item::is_valid)
     }
+
+    #[inline]
+    fn __canonicalize__(it: &'_ mut Self::CLayout) {
+        it.iter_mut().for_each(Item::__canonicalize__)
+    }
+}
+
+#[cfg(feature = "headers")]
+#[test]
+fn atomics() {
+    use ::core::sync::atomic::AtomicU32;
+
+    use crate::headers::languages::CSharp;
+    use crate::headers::languages::C;
+
+    assert_eq!(CLayoutOf::<AtomicU32>::name(&C), "SAFER_FFI_ATOMIC(uint32_t)");
+    assert_eq!(CLayoutOf::<AtomicU32>::name(&CSharp), "/* atomic */ UInt32");
+    assert!(unsafe { from_raw::<::core::sync::atomic::AtomicBool>(AtomicLayout(Bool(2))) }.is_none());
 }
@@ -1,4 +1,5 @@
 export_cfgs! {$
+    "alloc" => __cfg_alloc__!,
     "headers" => __cfg_headers__!,
     "js" => __cfg_js__!,
     "python-headers" => __cfg_python__!,
@@ -16,6 +16,13 @@ unsafe impl<T: ReprC + HasNiche> ReprC for Option<T> {
     fn is_valid(it: &'_ Self::CLayout) -> bool {
         T::is_niche(it) || <T as ReprC>::is_valid(it)
     }
+
+    #[inline]
+    fn __canonicalize__(it: &'_ mut Self::CLayout) {
+        if T::is_niche(it).not() {
+            <T as ReprC>::__canonicalize__(it)
+        }
+    }
 }
 
 #[cfg_attr(rustfmt, rustfmt::skip)]
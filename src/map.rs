@@ -0,0 +1,352 @@
+//! FFI-safe map types: [`HashMap`] and [`BTreeMap`].
+//!
+//! These are _opaque_ types, meant to be manipulated from C through pointers
+//! (_e.g._, `repr_c::Box<repr_c::HashMap<K, V>>` as the owned handle), and
+//! through the `get` / `insert` / `remove` / `len` / iteration functions
+//! which [`ffi_export_map_helpers!`] generates for a given `K, V`
+//! instantiation:
+//!
+//! ```rust
+//! use ::safer_ffi::prelude::*;
+//!
+//! // Exports `scores_new()`, `scores_get()`, `scores_insert()`, _etc._
+//! ::safer_ffi::ffi_export_map_helpers! {
+//!     scores: HashMap<i32, f64>
+//! }
+//!
+//! #[ffi_export]
+//! fn best_score(scores: &'_ repr_c::HashMap<i32, f64>) -> f64 {
+//!     scores.values().copied().fold(f64::NEG_INFINITY, f64::max)
+//! }
+//! ```
+//!
+//! For bulk transfers, the entries of a map can be copied into a
+//! [`c_slice::Box`] of borrowing [`KeyValue`] pairs (see
+//! [`HashMap::entries()`]), and owned entries coming from C can be collected
+//! back into a map.
+
+use_prelude!();
+
+ReprC! {
+    #[repr(C)]
+    /// A key-value pair, as found in the [`entries()`][HashMap::entries] of
+    /// a map.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    pub
+    struct KeyValue[K, V] {
+        pub key: K,
+        pub value: V,
+    }
+}
+
+impl<K, V> From<(K, V)> for KeyValue<K, V> {
+    #[inline]
+    fn from((key, value): (K, V)) -> KeyValue<K, V> {
+        Self { key, value }
+    }
+}
+
+impl<K, V> From<KeyValue<K, V>> for (K, V) {
+    #[inline]
+    fn from(KeyValue { key, value }: KeyValue<K, V>) -> (K, V) {
+        (key, value)
+    }
+}
+
+#[cfg_attr(rustfmt, rustfmt::skip)]
+macro_rules! map_types {(
+    $(
+        $(#[$cfg:meta])?
+        $Map:ident, $Map_Layout:ident => $StdMap:ident [$($KBounds:tt)*]
+    ),* $(,)?
+) => ($(
+    $(#[$cfg])?
+    /// An FFI-safe, opaque, wrapper around a standard-library
+    #[doc = concat!("[`", stringify!($Map), "`][`", stringify!($StdMap), "`].")]
+    ///
+    /// Its C layout is that of an opaque `struct` (named after `K` and `V`),
+    /// so it is to be used behind a pointer, such as a `repr_c::Box` or a
+    /// reference.
+    ///
+    /// It derefs to the standard map, and can be converted from and into it
+    /// for free.
+    #[repr(transparent)]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct $Map<K, V> {
+        inner: $StdMap<K, V>,
+    }
+
+    $(#[$cfg])?
+    #[doc = concat!("The (opaque) C layout of a [`", stringify!($Map), "`].")]
+    pub struct $Map_Layout<K: CType, V: CType>(PhantomData<fn() -> (K, V)>);
+
+    $(#[$cfg])?
+    const _: () = {
+        impl<K, V> $Map<K, V> {
+            /// Unwraps the standard map.
+            #[inline]
+            pub fn into_inner(self: Self) -> $StdMap<K, V> {
+                self.inner
+            }
+
+            /// The entries of the map, as a [`c_slice::Box`] of pairs of
+            /// pointers into the map, for bulk transfer through FFI.
+            ///
+            /// Since the entries of the map are not contiguous, this
+            /// allocates (and fills) an `O(n)` buffer on every call.
+            pub fn entries(self: &'_ Self) -> c_slice::Box<KeyValue<&'_ K, &'_ V>> {
+                self.inner
+                    .iter()
+                    .map(KeyValue::from)
+                    .collect::<rust::Box<[_]>>()
+                    .into()
+            }
+        }
+
+        impl<K, V> Default for $Map<K, V>
+        where
+            $StdMap<K, V>: Default,
+        {
+            #[inline]
+            fn default() -> Self {
+                $StdMap::default().into()
+            }
+        }
+
+        impl<K, V> From<$StdMap<K, V>> for $Map<K, V> {
+            #[inline]
+            fn from(inner: $StdMap<K, V>) -> $Map<K, V> {
+                Self { inner }
+            }
+        }
+
+        impl<K, V> From<$Map<K, V>> for $StdMap<K, V> {
+            #[inline]
+            fn from(map: $Map<K, V>) -> $StdMap<K, V> {
+                map.inner
+            }
+        }
+
+        /// Collects owned entries (_e.g._, coming from C) into a map.
+        impl<K: $($KBounds)*, V> From<repr_c::Vec<KeyValue<K, V>>> for $Map<K, V> {
+            fn from(entries: repr_c::Vec<KeyValue<K, V>>) -> $Map<K, V> {
+                rust::Vec::from(entries).into_iter().collect()
+            }
+        }
+
+        impl<K: $($KBounds)*, V> FromIterator<KeyValue<K, V>> for $Map<K, V> {
+            fn from_iter<I: IntoIterator<Item = KeyValue<K, V>>>(entries: I) -> $Map<K, V> {
+                entries
+                    .into_iter()
+                    .map(<(K, V)>::from)
+                    .collect::<$StdMap<K, V>>()
+                    .into()
+            }
+        }
+
+        impl<K, V> Deref for $Map<K, V> {
+            type Target = $StdMap<K, V>;
+
+            #[inline]
+            fn deref(self: &'_ $Map<K, V>) -> &'_ $StdMap<K, V> {
+                &self.inner
+            }
+        }
+
+        impl<K, V> DerefMut for $Map<K, V> {
+            #[inline]
+            fn deref_mut(self: &'_ mut $Map<K, V>) -> &'_ mut $StdMap<K, V> {
+                &mut self.inner
+            }
+        }
+
+        unsafe impl<K: ReprC, V: ReprC> ReprC for $Map<K, V> {
+            type CLayout = $Map_Layout<K::CLayout, V::CLayout>;
+
+            fn is_valid(_: &'_ Self::CLayout) -> bool {
+                unreachable! {"\
+                    wondering about the validity of an opaque type \
+                    makes no sense\
+                "};
+            }
+        }
+
+        impl<K: CType, V: CType> Clone for $Map_Layout<K, V> {
+            #[inline]
+            fn clone(self: &'_ Self) -> Self {
+                *self
+            }
+        }
+        impl<K: CType, V: CType> Copy for $Map_Layout<K, V> {}
+
+        unsafe impl<K: CType, V: CType> CType for $Map_Layout<K, V> {
+            type OPAQUE_KIND = OpaqueKind::Opaque;
+
+            __cfg_headers__! {
+                fn short_name() -> rust::String {
+                    format!(
+                        concat!(stringify!($Map), "_{}_{}"),
+                        K::short_name(),
+                        V::short_name(),
+                    )
+                }
+
+                fn define_self__impl(
+                    language: &'_ dyn HeaderLanguage,
+                    definer: &'_ mut dyn Definer,
+                ) -> io::Result<()>
+                {
+                    language.declare_opaque_type(
+                        language,
+                        definer,
+                        &[
+                            &format!(
+                                "An opaque `{}`.",
+                                ::core::any::type_name::<$StdMap<K, V>>(),
+                            ),
+                        ],
+                        &PhantomData::<Self>,
+                    )
+                }
+            }
+        }
+        from_CType_impl_ReprC! { @for[K: CType, V: CType] $Map_Layout<K, V> }
+    };
+)*)}
+
+use ::alloc::collections::BTreeMap as StdBTreeMap;
+#[cfg(feature = "std")]
+use ::std::collections::HashMap as StdHashMap;
+
+map_types! {
+    #[cfg(feature = "std")]
+    HashMap, HashMap_Layout => StdHashMap [::core::hash::Hash + Eq],
+    BTreeMap, BTreeMap_Layout => StdBTreeMap [Ord],
+}
+
+/// Exports, for the given `K, V` instantiation of a map type, the
+/// `extern "C"` functions needed to manipulate it from C.
+///
+/// Given `prefix: HashMap<K, V>` (or `BTreeMap<K, V>`), the following
+/// functions are [`#[ffi_export]`][crate::ffi_export]ed:
+///
+///   - `prefix_new()`, returning a new, empty, owned map,
+///   - `prefix_free(map)`,
+///   - `prefix_len(&map)`,
+///   - `prefix_get(&map, &key)`, returning a nullable pointer to the value,
+///   - `prefix_insert(&mut map, key, value)`, returning whether a previous
+///     value was replaced (and freed),
+///   - `prefix_remove(&mut map, &key)`, returning whether a value was removed
+///     (and freed),
+///   - `prefix_for_each(&map, callback)`, calling back with each key and
+///     value,
+///   - `prefix_entries(&map)`, returning an (allocated) slice of
+///     [`KeyValue`] pointers into the map, to be freed with
+///     `prefix_entries_free()`.
+///
+/// See [the module docs][crate::map] for an example.
+#[macro_export]
+#[cfg_attr(rustfmt, rustfmt::skip)]
+macro_rules! ffi_export_map_helpers {(
+    $prefix:ident : $Map:ident < $K:ty, $V:ty $(,)? > $(,)?
+) => ($crate::paste::item! {
+    const _: () = {
+        type Map = $crate::map::$Map<$K, $V>;
+        type Entries<'map> = $crate::prelude::c_slice::Box<
+            $crate::map::KeyValue<&'map $K, &'map $V>,
+        >;
+
+        /// Returns a new, empty, map, to be freed with
+        #[doc = concat!("`", stringify!([<$prefix _free>]), "()`.")]
+        #[$crate::ffi_export]
+        fn [<$prefix _new>] ()
+          -> $crate::prelude::repr_c::Box<Map>
+        {
+            $crate::boxed::ThinBox::new(Map::default())
+        }
+
+        /// Frees the map, along with its entries.
+        #[$crate::ffi_export]
+        fn [<$prefix _free>] (
+            map: $crate::prelude::repr_c::Box<Map>,
+        )
+        {
+            $crate::ඞ::drop(map)
+        }
+
+        /// Returns the number of entries in the map.
+        #[$crate::ffi_export]
+        fn [<$prefix _len>] (
+            map: &'_ Map,
+        ) -> usize
+        {
+            map.len()
+        }
+
+        /// Returns a pointer to the value associated to `key`, or `NULL` if
+        /// there is none.
+        #[$crate::ffi_export]
+        fn [<$prefix _get>]<'map> (
+            map: &'map Map,
+            key: &'_ $K,
+        ) -> $crate::ඞ::Option<&'map $V>
+        {
+            map.get(key)
+        }
+
+        /// Inserts `value` under `key`, returning whether a previous value
+        /// was replaced (in which case it gets freed).
+        #[$crate::ffi_export]
+        fn [<$prefix _insert>] (
+            map: &'_ mut Map,
+            key: $K,
+            value: $V,
+        ) -> bool
+        {
+            map.insert(key, value).is_some()
+        }
+
+        /// Removes (and frees) the value associated to `key`, returning
+        /// whether there was one.
+        #[$crate::ffi_export]
+        fn [<$prefix _remove>] (
+            map: &'_ mut Map,
+            key: &'_ $K,
+        ) -> bool
+        {
+            map.remove(key).is_some()
+        }
+
+        /// Calls `callback` with each key and value of the map.
+        #[$crate::ffi_export]
+        fn [<$prefix _for_each>] (
+            map: &'_ Map,
+            callback: $crate::closure::RefDynFnMut2<'_, (), &'_ $K, &'_ $V>,
+        )
+        {
+            let mut callback = callback;
+            map.iter().for_each(|(key, value)| callback.call(key, value))
+        }
+
+        /// Returns (an allocated slice of) pointers to all the entries of the
+        /// map, to be freed with
+        #[doc = concat!("`", stringify!([<$prefix _entries_free>]), "()`.")]
+        #[$crate::ffi_export]
+        fn [<$prefix _entries>]<'map> (
+            map: &'map Map,
+        ) -> Entries<'map>
+        {
+            map.entries()
+        }
+
+        /// Frees the entries returned by
+        #[doc = concat!("`", stringify!([<$prefix _entries>]), "()`.")]
+        #[$crate::ffi_export]
+        fn [<$prefix _entries_free>] (
+            entries: Entries<'_>,
+        )
+        {
+            $crate::ඞ::drop(entries)
+        }
+    };
+})}
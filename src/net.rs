@@ -0,0 +1,215 @@
+//! FFI-safe versions of [`core::net::IpAddr`] and [`core::net::SocketAddr`].
+
+use_prelude!();
+
+use ::core::net::Ipv4Addr;
+use ::core::net::Ipv6Addr;
+use ::core::net::SocketAddrV4;
+use ::core::net::SocketAddrV6;
+
+/// The version of an [`IpAddr`].
+#[derive_ReprC]
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum IpFamily {
+    V4 = 4,
+    V6 = 6,
+}
+
+mod c_layout {
+    use super::*;
+
+    #[derive_ReprC]
+    #[repr(C)]
+    pub struct IpAddr {
+        pub family: IpFamily,
+        pub octets: [u8; 16],
+    }
+}
+
+pub use c_layout::IpAddr_Layout;
+
+/// An FFI-safe version of [`core::net::IpAddr`], converting to and from it
+/// when necessary.
+///
+/// It is represented as a tagged address, wherein IPv4 addresses only use
+/// the first 4 `octets` (the other ones being zeroed):
+///
+/// ```C
+/// typedef struct IpAddr {
+///     IpFamily_t family;
+///     uint8_t octets[16];
+/// } IpAddr_t;
+/// ```
+///
+/// When received from C, the trailing 12 `octets` of an IPv4 address are
+/// checked to be zeroed, so that equal addresses compare (and hash) equal.
+///
+/// In JS, it is represented as a string, such as `"127.0.0.1"` or `"::1"`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct IpAddr {
+    family: IpFamily,
+    /// In network (big-endian) order.
+    octets: [u8; 16],
+}
+
+unsafe impl ReprC for IpAddr {
+    type CLayout = CLayoutOf<c_layout::IpAddr>;
+
+    #[inline]
+    fn is_valid(it: &'_ Self::CLayout) -> bool {
+        <c_layout::IpAddr as ReprC>::is_valid(it)
+            && (it.family.discriminant != IpFamily::V4 as u8
+                || it.octets[4 ..].iter().all(|&octet| octet == 0))
+    }
+}
+
+impl From<::core::net::IpAddr> for IpAddr {
+    fn from(ip: ::core::net::IpAddr) -> IpAddr {
+        match ip {
+            | ::core::net::IpAddr::V4(ip) => {
+                let mut octets = [0; 16];
+                octets[.. 4].copy_from_slice(&ip.octets());
+                Self {
+                    family: IpFamily::V4,
+                    octets,
+                }
+            },
+            | ::core::net::IpAddr::V6(ip) => Self {
+                family: IpFamily::V6,
+                octets: ip.octets(),
+            },
+        }
+    }
+}
+
+impl From<IpAddr> for ::core::net::IpAddr {
+    fn from(IpAddr { family, octets }: IpAddr) -> ::core::net::IpAddr {
+        match family {
+            | IpFamily::V4 => {
+                let [a, b, c, d, ..] = octets;
+                Ipv4Addr::new(a, b, c, d).into()
+            },
+            | IpFamily::V6 => Ipv6Addr::from(octets).into(),
+        }
+    }
+}
+
+impl IpAddr {
+    #[inline]
+    pub fn family(self: &'_ IpAddr) -> IpFamily {
+        self.family
+    }
+
+    /// In network (big-endian) order, with only the first 4 of them being
+    /// meaningful (the other ones being zeroed) for an IPv4 address.
+    #[inline]
+    pub fn octets(self: &'_ IpAddr) -> [u8; 16] {
+        self.octets
+    }
+
+    /// Converts `self` into a standard Rust [`IpAddr`][::core::net::IpAddr].
+    #[inline]
+    pub fn into_rust(self: IpAddr) -> ::core::net::IpAddr {
+        self.into()
+    }
+}
+
+/// An FFI-safe version of [`core::net::SocketAddr`], converting to and from
+/// it when necessary.
+///
+/// Note that the `flowinfo` and `scope_id` of IPv6 socket addresses are not
+/// preserved (they are zeroed when converting back).
+///
+/// In JS, it is represented as a string, such as `"127.0.0.1:8080"` or
+/// `"[::1]:8080"`.
+#[derive_ReprC]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SocketAddr {
+    pub ip: IpAddr,
+    /// In native endianness.
+    pub port: u16,
+}
+
+impl From<::core::net::SocketAddr> for SocketAddr {
+    #[inline]
+    fn from(addr: ::core::net::SocketAddr) -> SocketAddr {
+        Self {
+            ip: addr.ip().into(),
+            port: addr.port(),
+        }
+    }
+}
+
+impl From<SocketAddr> for ::core::net::SocketAddr {
+    fn from(SocketAddr { ip, port }: SocketAddr) -> ::core::net::SocketAddr {
+        match ip.into_rust() {
+            | ::core::net::IpAddr::V4(ip) => SocketAddrV4::new(ip, port).into(),
+            | ::core::net::IpAddr::V6(ip) => SocketAddrV6::new(ip, port, 0, 0).into(),
+        }
+    }
+}
+
+impl SocketAddr {
+    /// Converts `self` into a standard Rust [`SocketAddr`][::core::net::SocketAddr].
+    #[inline]
+    pub fn into_rust(self: SocketAddr) -> ::core::net::SocketAddr {
+        self.into()
+    }
+}
+
+#[cfg(feature = "js")]
+const _: () = {
+    use crate::js::*;
+
+    match_! {(
+        IpAddr_Layout => IpAddr => ::core::net::IpAddr,
+        SocketAddr_Layout => SocketAddr => ::core::net::SocketAddr,
+    ) {(
+        $( $Layout:ident => $ReprC:ident => $Std:ty ),* $(,)?
+    ) => (
+        $(
+            impl ReprNapi for $Layout {
+                type NapiValue = JsString;
+
+                fn to_napi_value(
+                    self: Self,
+                    env: &'_ Env,
+                ) -> Result<JsString> {
+                    let it = unsafe { crate::layout::from_raw::<$ReprC>(self) }.ok_or_else(|| {
+                        Error::from_reason(concat!("invalid `", stringify!($ReprC), "`"))
+                    })?;
+                    env.create_string(&it.into_rust().to_string())
+                }
+
+                fn from_napi_value(
+                    _env: &'_ Env,
+                    js_string: JsString,
+                ) -> Result<Self> {
+                    let it: $Std = js_string.into_utf8()?.as_str()?.parse().map_err(|err| {
+                        Error::new(Status::InvalidArg, format!("{err}"))
+                    })?;
+                    Ok(unsafe { crate::layout::into_raw($ReprC::from(it)) })
+                }
+            }
+        )*
+    )}}
+};
+
+#[test]
+fn roundtrips() {
+    let ip: ::core::net::IpAddr = Ipv4Addr::new(127, 0, 0, 1).into();
+    let c_ip = IpAddr::from(ip);
+    assert_eq!(c_ip.octets()[.. 5], [127, 0, 0, 1, 0]);
+    assert_eq!(c_ip.into_rust(), ip);
+
+    let mut raw = unsafe { crate::layout::into_raw(c_ip) };
+    assert_eq!(unsafe { crate::layout::from_raw::<IpAddr>(raw) }, Some(c_ip));
+    raw.octets[15] = 1;
+    assert!(unsafe { crate::layout::from_raw::<IpAddr>(raw) }.is_none());
+
+    let addr: ::core::net::SocketAddr = "[::1]:8080".parse().unwrap();
+    assert_eq!(SocketAddr::from(addr).into_rust(), addr);
+}
@@ -40,6 +40,16 @@ where
             | _ => false,
         }
     }
+
+    fn __canonicalize__(it: &'_ mut Self::CLayout) {
+        if unsafe { ::core::mem::transmute_copy::<_, u8>(it) } == 1 {
+            T::__canonicalize__(unsafe {
+                &mut *(it as *mut _ as *mut u8)
+                    .add(core::mem::align_of::<T>())
+                    .cast()
+            })
+        }
+    }
 }
 
 impl<T> From<core::option::Option<T>> for TaggedOption<T> {
@@ -150,3 +160,21 @@ fn option() {
         0
     );
 }
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for TaggedOption<T> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.as_ref(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for TaggedOption<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        serde::Deserialize::deserialize(deserializer)
+            .map(|it: ::core::option::Option<T>| it.into())
+    }
+}
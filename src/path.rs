@@ -0,0 +1,502 @@
+//! FFI-safe versions of [`Path`] and [`PathBuf`][::std::path::PathBuf].
+//!
+//! Paths are passed as (length-prefixed) bytes, with the following encoding:
+//!
+//!   - on Unix, the raw bytes of the [`OsStr`], so that any path (including
+//!     non-UTF-8 ones) round-trips losslessly;
+//!
+//!   - elsewhere, UTF-8, or rather, its WTF-8 superset (so that, on Windows,
+//!     ill-formed UTF-16 paths may still round-trip through Rust).
+//!
+//! In both cases, well-formed UTF-8 is a valid path, so C code may always
+//! pass in (and expect) UTF-8 for Unicode paths.
+//!
+//! Much like with [`str_ref`][crate::string::str_ref] and UTF-8, the encoding
+//! of a path received from C is trusted, rather than checked, since
+//! [`ReprC::is_valid`] is not to read the bytes: use the checked
+//! [`path_ref::from_bytes`] constructor when in doubt.
+
+use_prelude!();
+use ::std::ffi::OsStr;
+use ::std::path::Path;
+
+use crate::slice::slice_ref;
+
+/// Whether `bytes` are in the [encoding of this module][self].
+#[cfg(unix)]
+#[inline]
+fn is_valid_encoding(_: &'_ [u8]) -> bool {
+    true
+}
+
+/// Whether `bytes` are [WTF-8](https://simonsapin.github.io/wtf-8/), _i.e._,
+/// UTF-8 but for also allowing surrogate code points, provided they are
+/// unpaired (a surrogate pair is to be encoded as its 4-byte code point).
+#[cfg(not(unix))]
+fn is_valid_encoding(mut bytes: &'_ [u8]) -> bool {
+    let mut after_lead_surrogate = false;
+    loop {
+        let valid_up_to = match ::core::str::from_utf8(bytes) {
+            | Ok(_) => return true,
+            | Err(err) => err.valid_up_to(),
+        };
+        if valid_up_to > 0 {
+            after_lead_surrogate = false;
+        }
+        match bytes[valid_up_to ..] {
+            | [0xed, second @ 0xa0 ..= 0xbf, 0x80 ..= 0xbf, ..] => {
+                let is_lead = second < 0xb0;
+                if is_lead.not() && after_lead_surrogate {
+                    return false;
+                }
+                after_lead_surrogate = is_lead;
+                bytes = &bytes[valid_up_to + 3 ..];
+            },
+            | _ => return false,
+        }
+    }
+}
+
+/// The error of [`path_ref::from_bytes`]: the bytes are not in the
+/// [encoding of this module][self].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidEncoding;
+
+impl fmt::Display for InvalidEncoding {
+    fn fmt(
+        self: &'_ Self,
+        fmt: &'_ mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        fmt::Display::fmt("Invalid path encoding (expected WTF-8)", fmt)
+    }
+}
+
+impl ::std::error::Error for InvalidEncoding {}
+
+/// Safety: `bytes` must be in the [encoding of this module][self].
+#[cfg(unix)]
+#[inline]
+unsafe fn os_str_from_bytes(bytes: &'_ [u8]) -> &'_ OsStr {
+    ::std::os::unix::ffi::OsStrExt::from_bytes(bytes)
+}
+
+/// Safety: `bytes` must be in the [encoding of this module][self].
+#[cfg(not(unix))]
+#[inline]
+unsafe fn os_str_from_bytes(bytes: &'_ [u8]) -> &'_ OsStr {
+    unsafe { OsStr::from_encoded_bytes_unchecked(bytes) }
+}
+
+/// `&'lt Path`, but with a guaranteed `#[repr(C)]` layout, of a
+/// `slice_ref_uint8_t` (in the [encoding of this module][self]).
+///
+/// In JS, it is represented as a string.
+///
+/// # Validity
+///
+/// When received from C, only the pointer and length are checked: the bytes
+/// are trusted to be in the [encoding of this module][self] (see
+/// [`path_ref::from_bytes`] for a checked constructor).
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct path_ref<'lt>(slice_ref<'lt, u8>);
+
+/// The `CLayout` of a [`path_ref`]: that of a `slice_ref<'_, u8>`, but for
+/// JS, where it is a string.
+#[doc(hidden)]
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct path_ref_Layout<'lt>(CLayoutOf<slice_ref<'lt, u8>>);
+
+unsafe impl<'lt> CType for path_ref_Layout<'lt> {
+    type OPAQUE_KIND = OpaqueKind::Concrete;
+    __cfg_alloc__! {
+        fn describe() -> TypeDescriptor {
+            <CLayoutOf<slice_ref<'lt, u8>> as CType>::describe()
+        }
+    }
+    __cfg_headers__! {
+        fn short_name() -> String {
+            <CLayoutOf<slice_ref<'lt, u8>> as CType>::short_name()
+        }
+
+        fn define_self__impl(
+            _language: &'_ dyn HeaderLanguage,
+            _definer: &'_ mut dyn Definer,
+        ) -> io::Result<()>
+        {
+            unimplemented!("directly did `define_self()`");
+        }
+
+        fn define_self(
+            language: &'_ dyn HeaderLanguage,
+            definer: &'_ mut dyn Definer,
+        ) -> io::Result<()>
+        {
+            <CLayoutOf<slice_ref<'lt, u8>> as CType>::define_self(language, definer)
+        }
+
+        fn render(
+            out: &'_ mut dyn io::Write,
+            language: &'_ dyn HeaderLanguage,
+        ) -> io::Result<()>
+        {
+            <CLayoutOf<slice_ref<'lt, u8>> as CType>::render(out, language)
+        }
+
+        fn metadata() -> &'static dyn Provider {
+            <CLayoutOf<slice_ref<'lt, u8>> as CType>::metadata()
+        }
+    }
+}
+
+from_CType_impl_ReprC! { @for['lt] path_ref_Layout<'lt> }
+
+unsafe impl<'lt> ReprC for path_ref<'lt> {
+    type CLayout = path_ref_Layout<'lt>;
+
+    #[inline]
+    fn is_valid(path_ref_Layout(it): &'_ path_ref_Layout<'lt>) -> bool {
+        <slice_ref<'lt, u8> as ReprC>::is_valid(it)
+    }
+
+    #[inline]
+    fn __canonicalize__(path_ref_Layout(it): &'_ mut path_ref_Layout<'lt>) {
+        <slice_ref<'lt, u8> as ReprC>::__canonicalize__(it)
+    }
+}
+
+impl<'lt> From<&'lt Path> for path_ref<'lt> {
+    #[inline]
+    fn from(path: &'lt Path) -> path_ref<'lt> {
+        Self(path.as_os_str().as_encoded_bytes().into())
+    }
+}
+
+impl<'lt> From<&'lt OsStr> for path_ref<'lt> {
+    #[inline]
+    fn from(s: &'lt OsStr) -> path_ref<'lt> {
+        Self::from(Path::new(s))
+    }
+}
+
+impl<'lt> From<&'lt str> for path_ref<'lt> {
+    #[inline]
+    fn from(s: &'lt str) -> path_ref<'lt> {
+        Self::from(Path::new(s))
+    }
+}
+
+impl<'lt> path_ref<'lt> {
+    /// Checked conversion from bytes, _e.g._, the ones of a path received
+    /// from C: fails if they are not in the [encoding of this module][self].
+    #[inline]
+    pub fn from_bytes(bytes: &'lt [u8]) -> Result<path_ref<'lt>, InvalidEncoding> {
+        if is_valid_encoding(bytes) {
+            Ok(Self(bytes.into()))
+        } else {
+            Err(InvalidEncoding)
+        }
+    }
+
+    #[inline]
+    pub fn as_path(self: path_ref<'lt>) -> &'lt Path {
+        Path::new(self.as_os_str())
+    }
+
+    #[inline]
+    pub fn as_os_str(self: path_ref<'lt>) -> &'lt OsStr {
+        unsafe { os_str_from_bytes(self.as_bytes()) }
+    }
+
+    /// The bytes of the path, in the [encoding of this module][self].
+    #[inline]
+    pub fn as_bytes(self: path_ref<'lt>) -> &'lt [u8] {
+        self.0.as_slice()
+    }
+
+    /// Fails if the path is not valid UTF-8.
+    #[inline]
+    pub fn to_str(self: path_ref<'lt>) -> Result<&'lt str, ::core::str::Utf8Error> {
+        ::core::str::from_utf8(self.as_bytes())
+    }
+
+    #[inline]
+    pub fn to_owned(self: path_ref<'lt>) -> PathBuf {
+        PathBuf(self.as_bytes().to_vec().into())
+    }
+}
+
+impl Deref for path_ref<'_> {
+    type Target = Path;
+
+    #[inline]
+    fn deref(self: &'_ Self) -> &'_ Path {
+        self.as_path()
+    }
+}
+
+impl AsRef<Path> for path_ref<'_> {
+    #[inline]
+    fn as_ref(self: &'_ Self) -> &'_ Path {
+        self.as_path()
+    }
+}
+
+impl fmt::Debug for path_ref<'_> {
+    fn fmt(
+        self: &'_ Self,
+        fmt: &'_ mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        fmt::Debug::fmt(self.as_path(), fmt)
+    }
+}
+
+/// Same as [`PathBuf`][::std::path::PathBuf], but with a guaranteed
+/// `#[repr(C)]` layout, that of a `Vec_uint8_t` (in the [encoding of this
+/// module][self]).
+///
+/// In JS, it is represented as a string.
+///
+/// # Validity
+///
+/// As with [`path_ref`], the bytes of a `PathBuf` received from C are trusted
+/// to be in the [encoding of this module][self].
+#[repr(transparent)]
+#[derive(Clone)]
+pub struct PathBuf(repr_c::Vec<u8>);
+
+/// The `CLayout` of a [`PathBuf`]: that of a `repr_c::Vec<u8>`, but for JS,
+/// where it is a string.
+#[doc(hidden)]
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct PathBuf_Layout(CLayoutOf<repr_c::Vec<u8>>);
+
+unsafe impl CType for PathBuf_Layout {
+    type OPAQUE_KIND = OpaqueKind::Concrete;
+    __cfg_alloc__! {
+        fn describe() -> TypeDescriptor {
+            <CLayoutOf<repr_c::Vec<u8>> as CType>::describe()
+        }
+    }
+    __cfg_headers__! {
+        fn short_name() -> String {
+            <CLayoutOf<repr_c::Vec<u8>> as CType>::short_name()
+        }
+
+        fn define_self__impl(
+            _language: &'_ dyn HeaderLanguage,
+            _definer: &'_ mut dyn Definer,
+        ) -> io::Result<()>
+        {
+            unimplemented!("directly did `define_self()`");
+        }
+
+        fn define_self(
+            language: &'_ dyn HeaderLanguage,
+            definer: &'_ mut dyn Definer,
+        ) -> io::Result<()>
+        {
+            <CLayoutOf<repr_c::Vec<u8>> as CType>::define_self(language, definer)
+        }
+
+        fn render(
+            out: &'_ mut dyn io::Write,
+            language: &'_ dyn HeaderLanguage,
+        ) -> io::Result<()>
+        {
+            <CLayoutOf<repr_c::Vec<u8>> as CType>::render(out, language)
+        }
+
+        fn metadata() -> &'static dyn Provider {
+            <CLayoutOf<repr_c::Vec<u8>> as CType>::metadata()
+        }
+    }
+}
+
+from_CType_impl_ReprC! { PathBuf_Layout }
+
+unsafe impl ReprC for PathBuf {
+    type CLayout = PathBuf_Layout;
+
+    #[inline]
+    fn is_valid(PathBuf_Layout(it): &'_ PathBuf_Layout) -> bool {
+        <repr_c::Vec<u8> as ReprC>::is_valid(it)
+    }
+}
+
+impl From<::std::path::PathBuf> for PathBuf {
+    #[inline]
+    fn from(path: ::std::path::PathBuf) -> PathBuf {
+        Self(path.into_os_string().into_encoded_bytes().into())
+    }
+}
+
+impl From<PathBuf> for ::std::path::PathBuf {
+    #[inline]
+    fn from(PathBuf(bytes): PathBuf) -> ::std::path::PathBuf {
+        let bytes: rust::Vec<u8> = bytes.into();
+        #[cfg(unix)]
+        let os_string = ::std::os::unix::ffi::OsStringExt::from_vec(bytes);
+        #[cfg(not(unix))]
+        let os_string = unsafe { ::std::ffi::OsString::from_encoded_bytes_unchecked(bytes) };
+        os_string.into()
+    }
+}
+
+impl From<rust::String> for PathBuf {
+    #[inline]
+    fn from(s: rust::String) -> PathBuf {
+        Self(rust::Vec::from(s).into())
+    }
+}
+
+impl<'lt> From<&'lt Path> for PathBuf {
+    #[inline]
+    fn from(path: &'lt Path) -> PathBuf {
+        path_ref::from(path).to_owned()
+    }
+}
+
+impl PathBuf {
+    #[inline]
+    pub fn as_path_ref(self: &'_ PathBuf) -> path_ref<'_> {
+        path_ref(self.0.as_ref())
+    }
+
+    #[inline]
+    pub fn as_path(self: &'_ PathBuf) -> &'_ Path {
+        self.as_path_ref().as_path()
+    }
+
+    /// Converts `self` into a standard Rust [`PathBuf`][::std::path::PathBuf].
+    #[inline]
+    pub fn into_rust(self: PathBuf) -> ::std::path::PathBuf {
+        self.into()
+    }
+}
+
+impl Deref for PathBuf {
+    type Target = Path;
+
+    #[inline]
+    fn deref(self: &'_ Self) -> &'_ Path {
+        self.as_path()
+    }
+}
+
+impl AsRef<Path> for PathBuf {
+    #[inline]
+    fn as_ref(self: &'_ Self) -> &'_ Path {
+        self.as_path()
+    }
+}
+
+impl fmt::Debug for PathBuf {
+    fn fmt(
+        self: &'_ Self,
+        fmt: &'_ mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        fmt::Debug::fmt(self.as_path(), fmt)
+    }
+}
+
+#[cfg(feature = "js")]
+const _: () = {
+    use crate::js::*;
+
+    impl ReprNapi for PathBuf_Layout {
+        type NapiValue = JsString;
+
+        fn to_napi_value(
+            self: Self,
+            env: &'_ Env,
+        ) -> Result<JsString> {
+            let path: PathBuf = unsafe { crate::layout::from_raw_unchecked(self) };
+            let s = path.as_path_ref().to_str().map_err(|_| {
+                Error::from_reason(format!("path {path:?} is not valid Unicode"))
+            })?;
+            env.create_string(s)
+        }
+
+        fn from_napi_value(
+            _env: &'_ Env,
+            js_string: JsString,
+        ) -> Result<Self> {
+            let s = js_string.into_utf8()?.into_owned()?;
+            Ok(unsafe { crate::layout::into_raw(PathBuf::from(s)) })
+        }
+    }
+
+    impl ReprNapi for path_ref_Layout<'_> {
+        type NapiValue = JsString;
+
+        fn to_napi_value(
+            self: Self,
+            env: &'_ Env,
+        ) -> Result<JsString> {
+            let path: path_ref<'_> = unsafe { crate::layout::from_raw_unchecked(self) };
+            let s = path.to_str().map_err(|_| {
+                Error::from_reason(format!("path {path:?} is not valid Unicode"))
+            })?;
+            env.create_string(s)
+        }
+
+        /// The bytes are copied into a `Buffer`, which the JS scope keeps
+        /// alive for the duration of the call.
+        fn from_napi_value(
+            env: &'_ Env,
+            js_string: JsString,
+        ) -> Result<Self> {
+            let s = js_string.into_utf8()?.into_owned()?;
+            let (bytes, _storage): (&[u8], _);
+            #[cfg(target_arch = "wasm32")] {
+                _storage = ();
+                let raw = rust::Box::into_raw(rust::Box::<[u8]>::from(s.into_bytes()));
+                env.__push_drop_glue(::scopeguard::guard(raw, |raw| unsafe {
+                    drop(rust::Box::from_raw(raw))
+                }));
+                bytes = unsafe { &*raw };
+            } /* else */
+            #[cfg(not(target_arch = "wasm32"))] {
+                // Safety: the `Buffer`, and thus its bytes, outlive this
+                // `JsBufferValue` handle.
+                _storage = env.create_buffer_copy(s.as_bytes())?;
+                bytes = unsafe { &*(&_storage[..] as *const [u8]) };
+            }
+            Ok(unsafe { crate::layout::into_raw(path_ref(bytes.into())) })
+        }
+    }
+};
+
+#[test]
+fn roundtrips() {
+    let path = Path::new("/tmp/some dir/file.txt");
+    assert_eq!(path_ref::from(path).as_path(), path);
+    assert_eq!(PathBuf::from(path).into_rust(), path);
+
+    #[cfg(unix)]
+    {
+        let non_utf8: &OsStr = ::std::os::unix::ffi::OsStrExt::from_bytes(b"caf\xe9");
+        let c_path = path_ref::from(non_utf8);
+        assert_eq!(c_path.as_bytes(), b"caf\xe9");
+        assert!(c_path.to_str().is_err());
+        assert_eq!(c_path.to_owned().into_rust().as_os_str(), non_utf8);
+    }
+
+    #[cfg(not(unix))]
+    {
+        assert!(is_valid_encoding("café".as_bytes()));
+        // Unpaired surrogates are fine (`U+D800`, then `U+DC00`, apart).
+        assert!(is_valid_encoding(b"\xed\xa0\x80a\xed\xb0\x80"));
+        // but not a surrogate pair (`U+D800 U+DC00`).
+        assert!(is_valid_encoding(b"\xed\xa0\x80\xed\xb0\x80").not());
+        assert!(is_valid_encoding(b"caf\xe9").not());
+        assert_eq!(path_ref::from_bytes(b"caf\xe9").err(), Some(InvalidEncoding));
+    }
+    assert_eq!(path_ref::from_bytes(b"file.txt").unwrap().as_path(), Path::new("file.txt"));
+
+    let path_buf = PathBuf::from(path);
+    assert_eq!(path_buf.as_path_ref().as_path(), path);
+}
@@ -14,7 +14,7 @@ pub(crate) fn derive(
 ) -> Result<TokenStream2> {
     let args: Args = parse2(args)?;
 
-    let input: DeriveInput = parse2(input)?;
+    let mut input: DeriveInput = parse2(input)?;
     let DeriveInput {
         ref attrs,
         ref vis,
@@ -36,6 +36,7 @@ pub(crate) fn derive(
             "`union`s are not supported yet" => union_token
         },
     }?;
+    super::repr_c::strip_ffi_attrs(&mut input.data);
     Ok(quote!(
         #input
 
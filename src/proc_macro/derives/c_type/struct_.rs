@@ -45,6 +45,8 @@ pub(crate) fn derive(
         // invoke the legacy `CType!` macro which is the one currently featuring
         // the js FFI glue generating logic.
         let (params, bounds) = generics.my_split();
+        let fields = &mut fields.clone();
+        fields.iter_mut().for_each(|f| f.attrs.retain(|attr| attr.path().is_ident("ffi").not()));
         ret.extend(quote!(
             ::safer_ffi::layout::CType! {
                 #[repr(C, js)]
@@ -63,19 +65,63 @@ pub(crate) fn derive(
         type OPAQUE_KIND = #OpaqueKind::Concrete;
     );
 
+    let ref each_field_descriptor = fields.iter().try_vmap(|f| {
+        Result::Ok({
+            let field_name = f.ident.as_ref().unwrap();
+            let ref field_name_str = super::repr_c::FfiAttrs::from_attrs(&f.attrs)?
+                .rename
+                .map_or_else(|| field_name.to_string(), |rename| rename.value());
+            let FieldTy = &f.ty;
+            quote!(
+                #ඞ::FieldDescriptor {
+                    name: #field_name_str,
+                    offset: #ඞ::mem::offset_of!(Self, #field_name),
+                    ty: <#FieldTy as #CType>::describe(),
+                }
+            )
+        })
+    })?;
+    impl_body.extend(quote!(
+        ::safer_ffi::__cfg_alloc__! {
+            fn describe ()
+              -> #ඞ::TypeDescriptor
+            {
+                #ඞ::TypeDescriptor::new::<Self>(#ඞ::TypeKind::Struct {
+                    fields: #ඞ::Vec::from([#(#each_field_descriptor),*]),
+                })
+            }
+        }
+    ));
+
     if cfg!(feature = "headers") {
         let EachGenericTy = generics.type_params().map(|it| &it.ident);
         let EachConstParam = generics.const_params().map(|param| &param.ident);
         let ref EachFieldTy = fields.iter().vmap(|Field { ty, .. }| ty);
-        let ref StructName_str = args.rename.map_or_else(
-            || StructName.to_string().into_token_stream(),
-            ToTokens::into_token_stream,
-        );
-
-        impl_body.extend(quote!(
-            fn short_name ()
-              -> #ඞ::String
-            {
+        let short_name_body = if let Some(template) = &args.rename_generic {
+            let each_piece = utils::parse_name_template(template, generics)?.into_iter().map(
+                |piece| match piece {
+                    | utils::NameTemplatePiece::Literal(s) => quote!(
+                        _ret.push_str(#s);
+                    ),
+                    | utils::NameTemplatePiece::TypeParam(T) => quote!(
+                        _ret.push_str(&<#CLayoutOf<#T> as #CType>::short_name());
+                    ),
+                    | utils::NameTemplatePiece::ConstParam(N) => quote!(
+                        _ret.push_str(&#ඞ::format!("{}", #N));
+                    ),
+                },
+            );
+            quote!(
+                let mut _ret = #ඞ::String::new();
+                #(#each_piece)*
+                _ret
+            )
+        } else {
+            let ref StructName_str = args.rename.map_or_else(
+                || StructName.to_string().into_token_stream(),
+                ToTokens::into_token_stream,
+            );
+            quote!(
                 let mut _ret = #ඞ::format!("{}", #StructName_str);
                 #(
                     _ret.push_str(&#ඞ::format!("_{}", <#CLayoutOf<#EachGenericTy> as #CType>::short_name()));
@@ -84,6 +130,14 @@ pub(crate) fn derive(
                     _ret.push_str(&#ඞ::format!("_{}", #EachConstParam));
                 )*
                 _ret
+            )
+        };
+
+        impl_body.extend(quote!(
+            fn short_name ()
+              -> #ඞ::String
+            {
+                #short_name_body
             }
         ));
 
@@ -92,10 +146,15 @@ pub(crate) fn derive(
         let ref each_field: Vec<Quote![StructField]> = (0..).zip(fields).try_vmap(|(i, f)| {
             Result::Ok({
                 let ref field_docs = utils::extract_docs(&f.attrs)?;
-                let ref field_name_str = f
-                    .ident
-                    .as_ref()
-                    .map_or_else(|| format!("_{i}"), Ident::to_string);
+                let ref field_name_str = match super::repr_c::FfiAttrs::from_attrs(&f.attrs)? {
+                    | super::repr_c::FfiAttrs {
+                        rename: Some(rename),
+                    } => rename.value(),
+                    | _ => f
+                        .ident
+                        .as_ref()
+                        .map_or_else(|| format!("_{i}"), Ident::to_string),
+                };
                 let FieldTy = &f.ty;
                 quote!(
                     #ඞ::StructField {
@@ -178,6 +237,9 @@ pub(crate) fn derive_transparent(
     let mut ret = quote!();
 
     let Args { rename, .. } = &args;
+    if let Some(template) = &args.rename_generic {
+        bail!("`rename_generic` is not supported on `#[repr(transparent)]` types" => template);
+    }
 
     let docs = utils::extract_docs(attrs)?;
 
@@ -200,6 +262,16 @@ pub(crate) fn derive_transparent(
         {
             type OPAQUE_KIND = <#CFieldTy as #ඞ::CType>::OPAQUE_KIND;
 
+            ::safer_ffi::__cfg_alloc__! {
+                fn describe ()
+                  -> #ඞ::TypeDescriptor
+                {
+                    #ඞ::TypeDescriptor::new::<Self>(
+                        <#CFieldTy as #ඞ::CType>::describe().kind,
+                    )
+                }
+            }
+
             ::safer_ffi::__cfg_headers__! {
                 fn short_name ()
                   -> #ඞ::String
@@ -1,4 +1,6 @@
 pub(super) use args::Args;
+pub(super) use args::FfiAttrs;
+pub(super) use args::strip_ffi_attrs;
 
 use super::*;
 mod args;
@@ -40,6 +42,7 @@ pub(crate) fn derive(
             "`union`s are not supported yet" => union_token
         },
     }?;
+    strip_ffi_attrs(&mut input.data);
     Ok(quote!(
         #input
 
@@ -3,11 +3,21 @@ use super::*;
 mod kw {
     ::syn::custom_keyword!(js);
     ::syn::custom_keyword!(rename);
+    ::syn::custom_keyword!(rename_generic);
+    ::syn::custom_keyword!(variant_naming);
 }
 
 pub(crate) struct Args {
     pub(crate) rename: Option<Expr![String]>,
 
+    /// A `"…{T}…"` template for the C name of a generic type, wherein each
+    /// `{T}` gets replaced with the `short_name()` of the given (type or
+    /// const) generic parameter.
+    pub(crate) rename_generic: Option<LitStr>,
+
+    /// The `VariantNaming` style of an `enum`'s variants.
+    pub(crate) variant_naming: Option<Ident>,
+
     pub(crate) js: Option<kw::js>,
 }
 
@@ -16,11 +26,43 @@ impl Parse for Args {
         let mut ret = Args {
             js: None,
             rename: None,
+            rename_generic: None,
+            variant_naming: None,
         };
 
         while input.is_empty().not() {
             let snoopy = input.lookahead1();
             match () {
+                | _case if snoopy.peek(kw::rename_generic) => {
+                    let _: kw::rename_generic = input.parse().unwrap();
+                    let _: Token![=] = input.parse()?;
+                    if ret.rename_generic.replace(input.parse()?).is_some() {
+                        return Err(input.error("duplicate attribute"));
+                    }
+                },
+                | _case if snoopy.peek(kw::variant_naming) => {
+                    let _: kw::variant_naming = input.parse().unwrap();
+                    let _: Token![=] = input.parse()?;
+                    let style: LitStr = input.parse()?;
+                    let VariantNaming @ _ = match &style.value()[..] {
+                        | "screaming_prefixed" => "ScreamingPrefixed",
+                        | "screaming" => "Screaming",
+                        | "prefixed" => "Prefixed",
+                        | "verbatim" => "Verbatim",
+                        | _ => bail! {
+                            "expected one of \
+                            `\"screaming_prefixed\"`, `\"screaming\"`, \
+                            `\"prefixed\"`, or `\"verbatim\"`" => style
+                        },
+                    };
+                    if ret
+                        .variant_naming
+                        .replace(Ident::new(VariantNaming, style.span()))
+                        .is_some()
+                    {
+                        return Err(input.error("duplicate attribute"));
+                    }
+                },
                 | _case if snoopy.peek(kw::rename) => {
                     let _: kw::rename = input.parse().unwrap();
                     let _: Token![=] = input.parse()?;
@@ -38,6 +80,67 @@ impl Parse for Args {
             let _: Option<Token![,]> = input.parse()?;
         }
 
+        if let (Some(rename), Some(_)) = (&ret.rename, &ret.rename_generic) {
+            return Err(Error::new_spanned(
+                rename,
+                "`rename` and `rename_generic` are mutually exclusive",
+            ));
+        }
+
         Ok(ret)
     }
 }
+
+/// Field-level and variant-level `#[ffi(…)]` attributes.
+pub(crate) struct FfiAttrs {
+    pub(crate) rename: Option<LitStr>,
+}
+
+impl Parse for FfiAttrs {
+    fn parse(input: ParseStream<'_>) -> Result<FfiAttrs> {
+        let mut ret = FfiAttrs { rename: None };
+
+        while input.is_empty().not() {
+            let snoopy = input.lookahead1();
+            match () {
+                | _case if snoopy.peek(kw::rename) => {
+                    let _: kw::rename = input.parse().unwrap();
+                    let _: Token![=] = input.parse()?;
+                    if ret.rename.replace(input.parse()?).is_some() {
+                        return Err(input.error("duplicate attribute"));
+                    }
+                },
+                | _default => return Err(snoopy.error()),
+            }
+            let _: Option<Token![,]> = input.parse()?;
+        }
+
+        Ok(ret)
+    }
+}
+
+impl FfiAttrs {
+    pub(crate) fn from_attrs(attrs: &'_ [Attribute]) -> Result<FfiAttrs> {
+        let mut ret = FfiAttrs { rename: None };
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("ffi")) {
+            let FfiAttrs { rename } = attr.parse_args()?;
+            if let Some(rename) = rename {
+                if ret.rename.replace(rename).is_some() {
+                    bail!("duplicate attribute" => attr);
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+/// `#[ffi(…)]` attributes are not real attributes: they need to be stripped
+/// from the fields and variants of the re-emitted input.
+pub(crate) fn strip_ffi_attrs(data: &'_ mut Data) {
+    let strip = |attrs: &mut Vec<Attribute>| attrs.retain(|attr| attr.path().is_ident("ffi").not());
+    match data {
+        | Data::Struct(DataStruct { fields, .. }) => fields.iter_mut().for_each(|f| strip(&mut f.attrs)),
+        | Data::Enum(DataEnum { variants, .. }) => variants.iter_mut().for_each(|v| strip(&mut v.attrs)),
+        | Data::Union(_) => {},
+    }
+}
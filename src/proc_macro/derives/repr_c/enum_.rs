@@ -22,6 +22,10 @@ pub(crate) fn derive(
 
     let mut ret = quote!();
 
+    if let Some(template) = &args.rename_generic {
+        bail!("`rename_generic` is only supported on generic `struct`s" => template);
+    }
+
     if let Some(payload) = variants
         .iter()
         .find(|Variant { fields, .. }| matches!(fields, Fields::Unit,).not())
@@ -100,6 +104,32 @@ pub(crate) fn derive(
         type OPAQUE_KIND = #OpaqueKind::Concrete;
     );
 
+    let ref each_variant_descriptor = variants.try_vmap(|v| {
+        Result::Ok({
+            let VariantName = &v.ident;
+            let ref VariantName_str = FfiAttrs::from_attrs(&v.attrs)?
+                .rename
+                .map_or_else(|| VariantName.to_string(), |rename| rename.value());
+            quote!(
+                #ඞ::VariantDescriptor {
+                    name: #VariantName_str,
+                    discriminant: #EnumName::#VariantName as #Int as #ඞ::i128,
+                }
+            )
+        })
+    })?;
+    impl_body.extend(quote!(
+        ::safer_ffi::__cfg_alloc__! {
+            fn describe ()
+              -> #ඞ::TypeDescriptor
+            {
+                #ඞ::TypeDescriptor::new::<Self>(#ඞ::TypeKind::Enum {
+                    variants: #ඞ::Vec::from([#(#each_variant_descriptor),*]),
+                })
+            }
+        }
+    ));
+
     let ref each_doc = utils::extract_docs(attrs)?;
 
     if cfg!(feature = "headers") {
@@ -112,6 +142,7 @@ pub(crate) fn derive(
                 languages::{
                     HeaderLanguage,
                     EnumVariant,
+                    VariantNaming,
                 },
             },
         };
@@ -122,7 +153,15 @@ pub(crate) fn derive(
         );
         let ref each_enum_variant = variants.try_vmap(|v| {
             Result::Ok({
-                let ref VariantName_str = v.ident.to_string();
+                let (ref VariantName_str, naming) = match FfiAttrs::from_attrs(&v.attrs)?.rename {
+                    | Some(rename) => (rename.value(), format_ident!("Verbatim")),
+                    | None => (
+                        v.ident.to_string(),
+                        args.variant_naming
+                            .clone()
+                            .unwrap_or_else(|| format_ident!("ScreamingPrefixed")),
+                    ),
+                };
                 let discriminant = if let Some((_eq, disc)) = &v.discriminant {
                     quote!(
                         #ඞ::Some(&(#disc, ).0 as _)
@@ -138,6 +177,7 @@ pub(crate) fn derive(
                         docs: &[#(#docs),*],
                         name: #VariantName_str,
                         discriminant: #discriminant,
+                        naming: #VariantNaming::#naming,
                     }
                 )
             })
@@ -41,6 +41,10 @@ pub(crate) fn derive(
         bail!("C requires that structs have at least one field");
     }
 
+    if let Some(style) = &args.variant_naming {
+        bail!("`variant_naming` only applies to `enum`s" => style);
+    }
+
     let mut ret = quote!();
 
     #[rustfmt::skip]
@@ -99,7 +103,12 @@ pub(crate) fn derive(
                     | Some(ref ident) => ident.clone(),
                     | None => format_ident!("_{}", i),
                 });
-                let each_field_docs = fields.iter().map(|f| docs_of(&f.attrs).vec());
+                let each_field_docs = fields.iter().map(|f| {
+                    f.attrs
+                        .iter()
+                        .filter(|a| a.path().is_ident("doc") || a.path().is_ident("ffi"))
+                        .vec()
+                });
                 parse_quote!({
                     #(
                         #(#each_field_docs)*
@@ -111,10 +120,16 @@ pub(crate) fn derive(
             semi_token: None,
         };
 
-        let rename = args.rename.unwrap_or_else(|| {
-            let s = StructName.to_string();
-            parse_quote!(#s)
-        });
+        let naming = match args.rename_generic {
+            | Some(template) => quote!(rename_generic = #template),
+            | None => {
+                let rename = args.rename.unwrap_or_else(|| {
+                    let s = StructName.to_string();
+                    parse_quote!(#s)
+                });
+                quote!(rename = #rename)
+            },
+        };
 
         // allow using `#()*` as `#()?`.
         let js = args.js.as_ref().map_or(&[][..], ::core::slice::from_ref);
@@ -122,7 +137,7 @@ pub(crate) fn derive(
         crate::derives::c_type::derive(
             quote!(
                 #(#js ,)*
-                rename = #rename,
+                #naming,
             ),
             c_type_def.into_token_stream(),
         )?
@@ -130,8 +145,8 @@ pub(crate) fn derive(
 
     // Impl ReprC to point to the just defined type
     ret.extend({
-        let EachFieldTy @ _ = EachFieldTy();
-        let each_field_name = each_field_name();
+        let EachFieldTy @ _ = EachFieldTy().vec();
+        let each_field_name = each_field_name().vec();
         let (intro_generics, fwd_generics, where_clauses) = ctype_generics.split_for_impl();
         quote!(
             #[allow(trivial_bounds)]
@@ -176,6 +191,16 @@ pub(crate) fn derive(
                     )*
                     _ret
                 }
+
+                #[inline]
+                fn __canonicalize__ (_it: &'_ mut Self::CLayout)
+                {
+                    #(
+                        <#EachFieldTy as #ReprC>::__canonicalize__(
+                            &mut _it.#each_field_name
+                        );
+                    )*
+                }
             }
         )
     });
@@ -211,6 +236,10 @@ pub(crate) fn derive_transparent(
 
     let mut ret = quote!();
 
+    if let Some(template) = &args.rename_generic {
+        bail!("`rename_generic` is not supported on `#[repr(transparent)]` types" => template);
+    }
+
     let FieldTy = match fields.iter().next() {
         | Some(f) => &f.ty,
         | None => bail! {
@@ -292,6 +321,12 @@ pub(crate) fn derive_transparent(
                     {
                         <#FieldTy as #ඞ::ReprC>::is_valid(&it.0)
                     }
+
+                    #[inline]
+                    fn __canonicalize__ (it: &'_ mut Self::CLayout)
+                    {
+                        <#FieldTy as #ඞ::ReprC>::__canonicalize__(&mut it.0)
+                    }
                 }
             )
         });
@@ -314,6 +349,12 @@ pub(crate) fn derive_transparent(
                 {
                     <#FieldTy as #ඞ::ReprC>::is_valid(it)
                 }
+
+                #[inline]
+                fn __canonicalize__ (it: &'_ mut Self::CLayout)
+                {
+                    <#FieldTy as #ඞ::ReprC>::__canonicalize__(it)
+                }
             }
         ));
         inner = quote!(it);
@@ -434,11 +475,37 @@ pub(crate) fn derive_opaque(
         #[cfg(feature = "headers")]
         let header_generation = {
             drop(header_generation);
-            let ref short_name: Quote![ String ] = match args.rename {
-                | Some(string_expr) => quote!(
+            let ref short_name: Quote![ String ] = match (args.rename, &args.rename_generic) {
+                | (Some(string_expr), _) => quote!(
                     #ඞ::From::from(#string_expr)
                 ),
-                | None => {
+                | (None, Some(template)) => {
+                    let each_piece = utils::parse_name_template(template, generics)?
+                        .into_iter()
+                        .map(|piece| match piece {
+                            | utils::NameTemplatePiece::Literal(s) => quote!(
+                                _ret.push_str(#s);
+                            ),
+                            // Opaque generic parameters need not be `CType`s.
+                            | utils::NameTemplatePiece::TypeParam(T) => quote!(
+                                let mut name = #ඞ::String::new();
+                                #ඞ::append_unqualified_name(
+                                    &mut name,
+                                    #ඞ::any::type_name::<#T>(),
+                                );
+                                _ret.push_str(name.trim_start_matches('_'));
+                            ),
+                            | utils::NameTemplatePiece::ConstParam(N) => quote!(
+                                _ret.push_str(&#ඞ::format!("{}", #N));
+                            ),
+                        });
+                    quote!(
+                        let mut _ret = #ඞ::String::new();
+                        #(#each_piece)*
+                        _ret
+                    )
+                },
+                | (None, None) => {
                     let ref StructName_str = StructName.to_string();
                     let EachGenericParam = generics.type_params().map(|p| &p.ident);
                     quote!(
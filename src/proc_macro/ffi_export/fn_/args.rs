@@ -5,6 +5,7 @@ pub(crate) struct Args {
     pub(crate) js: Option<Js>,
     pub(crate) executor: Option<Executor>,
     pub(crate) rename: Option<Rename>,
+    pub(crate) serde: Option<Serde>,
 }
 
 #[cfg_attr(not(feature = "js"), allow(dead_code))]
@@ -26,11 +27,24 @@ pub(crate) struct Rename {
     pub(crate) new_name: LitStr,
 }
 
+pub(crate) struct Serde {
+    pub(crate) kw: kw::serde,
+    pub(crate) _eq: Token![=],
+    pub(crate) format: SerdeFormat,
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum SerdeFormat {
+    Json,
+    Cbor,
+}
+
 mod kw {
     ::syn::custom_keyword!(async_worker);
     ::syn::custom_keyword!(executor);
     ::syn::custom_keyword!(js);
     ::syn::custom_keyword!(rename);
+    ::syn::custom_keyword!(serde);
 }
 
 impl Parse for Args {
@@ -91,6 +105,26 @@ impl Parse for Args {
                     });
                 },
 
+                | _case if snoopy.peek(kw::serde) => {
+                    if ret.serde.is_some() {
+                        return Err(input.error("duplicate parameter"));
+                    }
+                    ret.serde = Some(Serde {
+                        kw: input.parse().unwrap(),
+                        _eq: input.parse()?,
+                        format: {
+                            let it = input.parse::<LitStr>()?;
+                            match &it.value()[..] {
+                                | "json" => SerdeFormat::Json,
+                                | "cbor" => SerdeFormat::Cbor,
+                                | _ => bail! {
+                                    "expected `\"json\"` or `\"cbor\"`" => it,
+                                },
+                            }
+                        },
+                    });
+                },
+
                 | _default => return Err(snoopy.error()),
             }
             let _: Option<Token![,]> = input.parse()?;
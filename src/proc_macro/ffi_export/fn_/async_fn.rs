@@ -7,6 +7,7 @@ pub(super) fn export(
         executor,
         js,
         rename,
+        serde: _,
     }: Args,
     fun: &'_ ItemFn,
 ) -> Result<TokenStream2> {
@@ -6,6 +6,8 @@ mod args;
 #[cfg(feature = "async-fn")]
 mod async_fn;
 
+mod serde_fn;
+
 #[rustfmt::skip]
 const SUPPORTED_ABIS: &[&str] = &[
     "C",
@@ -21,6 +23,11 @@ pub(super) fn handle(
     args: Args,
     mut fun: ItemFn,
 ) -> Result<TokenStream2> {
+    // `serde = "…"` case: lowered to a byte-buffer-based `#[ffi_export]`.
+    if args.serde.is_some() {
+        return serde_fn::export(args, fun);
+    }
+
     // async fn case.
     if args.executor.is_some() || fun.sig.asyncness.is_some() {
         if true {
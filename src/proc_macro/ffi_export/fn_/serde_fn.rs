@@ -0,0 +1,154 @@
+use super::*;
+
+/// Lowers:
+///
+/// ```rust ,ignore
+/// #[ffi_export(serde = "json")]
+/// fn fname(arg: Arg) -> Ret { … }
+/// ```
+///
+/// to:
+///
+/// ```rust ,ignore
+/// fn fname(arg: Arg) -> Ret { … }
+///
+/// #[ffi_export(rename = "fname")]
+/// fn fname__serde__(arg: c_slice::Ref<'_, u8>) -> Option<c_slice::Box<u8>> {
+///     let ret = fname(__deserialize_arg__::<Json, _>(arg)?);
+///     __serialize_ret__::<Json, _>(&ret)
+/// }
+///
+/// #[ffi_export(rename = "fname_free")]
+/// fn fname_free__serde__(ret: Option<c_slice::Box<u8>>) { drop(ret) }
+/// ```
+pub(super) fn export(
+    Args {
+        executor,
+        js,
+        rename,
+        serde,
+    }: Args,
+    fun: ItemFn,
+) -> Result<TokenStream2> {
+    let Serde { kw: serde_kw, format, .. } = serde.unwrap();
+    if let Some(asyncness) = &fun.sig.asyncness {
+        bail!("`serde = …` does not support `async fn`s" => asyncness);
+    }
+    if let Some(Executor { kw, .. }) = &executor {
+        bail!("`serde = …` cannot be combined with `executor = …`" => kw);
+    }
+    if let Some(Js { kw, .. }) = &js {
+        bail!("`serde = …` cannot be combined with `js`" => kw);
+    }
+    if fun.sig.generics.params.is_empty().not() {
+        bail!("`serde = …` does not support generic functions" => fun.sig.generics);
+    }
+    if let Some(receiver) = fun.sig.receiver() {
+        bail!("methods are not supported" => receiver);
+    }
+
+    let (Format @ _, format_name) = match format {
+        | SerdeFormat::Json => (quote!(::safer_ffi::serde_transport::Json), "JSON"),
+        | SerdeFormat::Cbor => (quote!(::safer_ffi::serde_transport::Cbor), "CBOR"),
+    };
+    let export_name = match &rename {
+        | Some(Rename { new_name, .. }) => new_name.value(),
+        | None => fun.sig.ident.to_string(),
+    };
+    let free_name = format!("{export_name}_free");
+
+    let span = Span::mixed_site().located_at(serde_kw.span());
+    let fname = &fun.sig.ident;
+    let pub_ = &fun.vis;
+    let each_cfg = fun.attrs.iter().filter(|attr| attr.path().is_ident("cfg")).vec();
+    let (each_arg, each_arg_doc): (Vec<Ident>, Vec<String>) = fun
+        .sig
+        .inputs
+        .iter()
+        .enumerate()
+        .map(|(i, fn_arg)| match *fn_arg {
+            | FnArg::Typed(PatType { ref pat, ref ty, .. }) => {
+                let arg_name = match **pat {
+                    | Pat::Ident(PatIdent { ident: ref arg_name, .. }) => {
+                        format_ident!("{}", arg_name, span = span)
+                    },
+                    | _ => format_ident!("__arg_{}", i, span = span),
+                };
+                let doc = format!("  - `{}`: `{}`", arg_name, pretty_type(ty));
+                (arg_name, doc)
+            },
+            | FnArg::Receiver(_) => unreachable!(),
+        })
+        .unzip();
+    let ret_ty_doc = match &fun.sig.output {
+        | ReturnType::Default => "()".into(),
+        | ReturnType::Type(_, ty) => pretty_type(ty),
+    };
+
+    let mut each_doc = vec![
+        String::new(),
+        format!("# Serialized transport ({format_name})"),
+        String::new(),
+    ];
+    if each_arg.is_empty().not() {
+        each_doc.push(format!("Each parameter is a {format_name}-serialized buffer of:"));
+        each_doc.extend(each_arg_doc);
+        each_doc.push(String::new());
+    }
+    each_doc.push(format!(
+        "Returns a {format_name}-serialized `{ret_ty_doc}`, to be freed with \
+        `{free_name}()`, or `NULL` if (de)serialization failed."
+    ));
+    let free_doc = format!("Frees a buffer returned by `{export_name}()`.");
+    let each_attr = fun.attrs.iter().filter(|attr| attr.path().is_ident("doc").not());
+    let each_user_doc = fun.attrs.iter().filter(|attr| attr.path().is_ident("doc"));
+
+    let ffi_fname = format_ident!("{}__serde__", fname, span = span);
+    let ffi_free_fname = format_ident!("{}_free__serde__", fname, span = span);
+
+    Ok(quote_spanned!(span=>
+        #fun
+
+        #[::safer_ffi::ffi_export(rename = #export_name)]
+        #(#each_attr)*
+        #(#each_user_doc)*
+        #(#[doc = #each_doc])*
+        #[allow(nonstandard_style)]
+        #pub_
+        fn #ffi_fname (
+            #( #each_arg: ::safer_ffi::prelude::c_slice::Ref<'_, u8> ),*
+        ) -> ::safer_ffi::ඞ::Option<::safer_ffi::prelude::c_slice::Box<u8>>
+        {
+            let ret = #fname(#(
+                ::safer_ffi::serde_transport::__deserialize_arg__::<#Format, _>(#each_arg)?
+            ),*);
+            ::safer_ffi::serde_transport::__serialize_ret__::<#Format, _>(&ret)
+        }
+
+        #[::safer_ffi::ffi_export(rename = #free_name)]
+        #(#each_cfg)*
+        #[doc = #free_doc]
+        #[allow(nonstandard_style)]
+        #pub_
+        fn #ffi_free_fname (
+            ret: ::safer_ffi::ඞ::Option<::safer_ffi::prelude::c_slice::Box<u8>>,
+        )
+        {
+            ::safer_ffi::ඞ::drop(ret)
+        }
+    ))
+}
+
+/// `quote!`'s spacing (`Vec < u8 >`) is not that pleasant to read in the
+/// generated headers.
+fn pretty_type(ty: &'_ Type) -> String {
+    ty.to_token_stream()
+        .to_string()
+        .replace(" :: ", "::")
+        .replace(":: ", "::")
+        .replace(" <", "<")
+        .replace("< ", "<")
+        .replace(" >", ">")
+        .replace(" ,", ",")
+        .replace("& ", "&")
+}
@@ -195,6 +195,62 @@ pub(crate) fn extract_docs(attrs: &'_ [Attribute]) -> Result<Vec<Expr>> {
     Ok(doc_strings)
 }
 
+pub(crate) enum NameTemplatePiece {
+    Literal(String),
+    TypeParam(Ident),
+    ConstParam(Ident),
+}
+
+/// Parses a `rename_generic = "…{T}…"` template, making sure each `{…}`
+/// hole refers to one of the type or const generic parameters.
+///
+/// Literal braces can be written as `{{` and `}}`.
+pub(crate) fn parse_name_template(
+    template: &'_ LitStr,
+    generics: &'_ Generics,
+) -> Result<Vec<NameTemplatePiece>> {
+    let ref s = template.value();
+    let mut ret = vec![];
+    let mut literal = String::new();
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            | '{' if chars.as_str().starts_with('{') => {
+                chars.next();
+                literal.push('{');
+            },
+            | '}' if chars.as_str().starts_with('}') => {
+                chars.next();
+                literal.push('}');
+            },
+            | '{' => {
+                let rest = chars.as_str();
+                let Some(end) = rest.find('}') else {
+                    bail!("unterminated `{` in name template" => template);
+                };
+                let name = rest[..end].trim();
+                chars = rest[end + 1..].chars();
+                if literal.is_empty().not() {
+                    ret.push(NameTemplatePiece::Literal(::core::mem::take(&mut literal)));
+                }
+                if let Some(param) = generics.type_params().find(|p| p.ident == name) {
+                    ret.push(NameTemplatePiece::TypeParam(param.ident.clone()));
+                } else if let Some(param) = generics.const_params().find(|p| p.ident == name) {
+                    ret.push(NameTemplatePiece::ConstParam(param.ident.clone()));
+                } else {
+                    bail!(format!("`{name}` is not a type or const generic parameter") => template);
+                }
+            },
+            | '}' => bail!("unmatched `}` in name template" => template),
+            | _ => literal.push(c),
+        }
+    }
+    if literal.is_empty().not() {
+        ret.push(NameTemplatePiece::Literal(literal));
+    }
+    Ok(ret)
+}
+
 pub(crate) struct LazyQuote(
     pub(crate) fn() -> TokenStream2,
     pub(crate) ::core::cell::RefCell<Option<TokenStream2>>,
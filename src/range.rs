@@ -0,0 +1,45 @@
+//! An FFI-safe version of [`core::ops::Range`].
+
+use_prelude!();
+
+/// An FFI-safe version of [`core::ops::Range`], converting to and from it
+/// when necessary.
+///
+/// Like its standard counterpart, `start` is inclusive, `end` is exclusive,
+/// and the range is empty when `start >= end`.
+///
+/// ```C
+/// typedef struct Range_int32 {
+///     int32_t start;
+///     int32_t end;
+/// } Range_int32_t;
+/// ```
+#[derive_ReprC(js, rename_generic = "Range_{T}")]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Range<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T> From<::core::ops::Range<T>> for Range<T> {
+    #[inline]
+    fn from(::core::ops::Range { start, end }: ::core::ops::Range<T>) -> Range<T> {
+        Self { start, end }
+    }
+}
+
+impl<T> From<Range<T>> for ::core::ops::Range<T> {
+    #[inline]
+    fn from(Range { start, end }: Range<T>) -> ::core::ops::Range<T> {
+        start .. end
+    }
+}
+
+impl<T> Range<T> {
+    /// Converts `self` into a standard Rust [`Range`][::core::ops::Range].
+    #[inline]
+    pub fn into_rust(self: Range<T>) -> ::core::ops::Range<T> {
+        self.into()
+    }
+}
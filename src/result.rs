@@ -0,0 +1,326 @@
+//! An ABI-stable version of [`core::result::Result`].
+
+use_prelude!();
+
+/// An ABI-stable version of `core::result::Result`.
+/// Its usage is expected to be the same as a standard Result, converting to and from said Result
+/// when necessary.
+///
+/// It is laid out as a `uint8_t tag` (`0` for `Ok`, `1` for `Err`), followed by a `union` of the
+/// `Ok` and `Err` payloads:
+///
+/// ```C
+/// typedef union Result_int32_Error_payload {
+///     int32_t ok;
+///     Error_t err;
+/// } Result_int32_Error_payload_t;
+///
+/// typedef struct Result_int32_Error {
+///     uint8_t tag;
+///     Result_int32_Error_payload_t payload;
+/// } Result_int32_Error_t;
+/// ```
+#[repr(C, u8)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Result<T, E> {
+    Ok(T) = 0,
+    Err(E) = 1,
+}
+
+/// The C layout of a [`Result`].
+#[repr(C)]
+#[allow(missing_debug_implementations)]
+pub struct Result_Layout<T: CType, E: CType> {
+    pub tag: u8,
+    pub payload: ResultPayload_Layout<T, E>,
+}
+
+/// The C layout of the payload of a [`Result`].
+#[repr(C)]
+#[allow(missing_debug_implementations)]
+pub union ResultPayload_Layout<T: CType, E: CType> {
+    pub ok: T,
+    pub err: E,
+}
+
+impl<T: CType, E: CType> Clone for Result_Layout<T, E> {
+    #[inline]
+    fn clone(self: &'_ Self) -> Self {
+        *self
+    }
+}
+impl<T: CType, E: CType> Copy for Result_Layout<T, E> {}
+
+impl<T: CType, E: CType> Clone for ResultPayload_Layout<T, E> {
+    #[inline]
+    fn clone(self: &'_ Self) -> Self {
+        *self
+    }
+}
+impl<T: CType, E: CType> Copy for ResultPayload_Layout<T, E> {}
+
+unsafe impl<T: CType, E: CType> CType for Result_Layout<T, E> {
+    type OPAQUE_KIND = OpaqueKind::Concrete;
+
+    __cfg_alloc__! {
+        fn describe() -> TypeDescriptor {
+            TypeDescriptor::new::<Self>(TypeKind::Struct {
+                fields: rust::Vec::from([
+                    FieldDescriptor {
+                        name: "tag",
+                        offset: mem::offset_of!(Self, tag),
+                        ty: u8::describe(),
+                    },
+                    FieldDescriptor {
+                        name: "payload",
+                        offset: mem::offset_of!(Self, payload),
+                        ty: ResultPayload_Layout::<T, E>::describe(),
+                    },
+                ]),
+            })
+        }
+    }
+
+    __cfg_headers__! {
+        fn short_name() -> rust::String {
+            format!("Result_{}_{}", T::short_name(), E::short_name())
+        }
+
+        fn define_self__impl(
+            language: &'_ dyn HeaderLanguage,
+            definer: &'_ mut dyn Definer,
+        ) -> io::Result<()>
+        {
+            u8::define_self(language, definer)?;
+            ResultPayload_Layout::<T, E>::define_self(language, definer)?;
+            language.declare_struct(
+                language,
+                definer,
+                &[],
+                &PhantomData::<Self>,
+                &[
+                    crate::headers::languages::StructField {
+                        docs: &["`0` for `Ok`, `1` for `Err`."],
+                        name: "tag",
+                        ty: &PhantomData::<u8>,
+                    },
+                    crate::headers::languages::StructField {
+                        docs: &[],
+                        name: "payload",
+                        ty: &PhantomData::<ResultPayload_Layout<T, E>>,
+                    },
+                ],
+            )
+        }
+    }
+}
+from_CType_impl_ReprC! { @for[T: CType, E: CType] Result_Layout<T, E> }
+
+unsafe impl<T: CType, E: CType> CType for ResultPayload_Layout<T, E> {
+    type OPAQUE_KIND = OpaqueKind::Concrete;
+
+    __cfg_alloc__! {
+        fn describe() -> TypeDescriptor {
+            TypeDescriptor::new::<Self>(TypeKind::Union {
+                fields: rust::Vec::from([
+                    FieldDescriptor {
+                        name: "ok",
+                        offset: 0,
+                        ty: T::describe(),
+                    },
+                    FieldDescriptor {
+                        name: "err",
+                        offset: 0,
+                        ty: E::describe(),
+                    },
+                ]),
+            })
+        }
+    }
+
+    __cfg_headers__! {
+        fn short_name() -> rust::String {
+            format!("Result_{}_{}_payload", T::short_name(), E::short_name())
+        }
+
+        fn define_self__impl(
+            language: &'_ dyn HeaderLanguage,
+            definer: &'_ mut dyn Definer,
+        ) -> io::Result<()>
+        {
+            T::define_self(language, definer)?;
+            E::define_self(language, definer)?;
+            language.declare_union(
+                language,
+                definer,
+                &[],
+                &PhantomData::<Self>,
+                &[
+                    crate::headers::languages::StructField {
+                        docs: &[],
+                        name: "ok",
+                        ty: &PhantomData::<T>,
+                    },
+                    crate::headers::languages::StructField {
+                        docs: &[],
+                        name: "err",
+                        ty: &PhantomData::<E>,
+                    },
+                ],
+            )
+        }
+    }
+}
+from_CType_impl_ReprC! { @for[T: CType, E: CType] ResultPayload_Layout<T, E> }
+
+// `Ok` values are returned to JS, whereas `Err` ones are thrown (with their
+// string coercion as the error message).
+//
+// The JS -> Rust direction only ever yields `Ok` values: a thrown exception
+// never reaches a value position, so there is no JS value to map to an `Err`.
+#[cfg(feature = "js")]
+const _: () = {
+    use crate::js::*;
+
+    impl<T: CType + ReprNapi, E: CType + ReprNapi> ReprNapi for Result_Layout<T, E> {
+        type NapiValue = JsUnknown;
+
+        fn to_napi_value(
+            self: Self,
+            env: &'_ Env,
+        ) -> Result<JsUnknown> {
+            match self.tag {
+                | 0 => Ok(T::to_napi_value(unsafe { self.payload.ok }, env)?.into_unknown()),
+                | 1 => {
+                    let err = E::to_napi_value(unsafe { self.payload.err }, env)?.into_unknown();
+                    let reason = err.coerce_to_string()?.into_utf8()?.into_owned()?;
+                    Err(Error::new(Status::GenericFailure, reason))
+                },
+                | bad => Err(Error::new(
+                    Status::InvalidArg,
+                    format!("({:#x}: Result tag) != 0x0, 0x1", bad),
+                )),
+            }
+        }
+
+        /// Always yields an `Ok` value (see above).
+        fn from_napi_value(
+            env: &'_ Env,
+            napi_value: JsUnknown,
+        ) -> Result<Self> {
+            let napi_value = T::NapiValue::try_from(napi_value).map_err(|_| {
+                Error::new(
+                    Status::InvalidArg,
+                    format!(
+                        "Expected a value convertible to a `{}`",
+                        ::core::any::type_name::<T>(),
+                    ),
+                )
+            })?;
+            Ok(Self {
+                tag: 0,
+                payload: ResultPayload_Layout {
+                    ok: T::from_napi_value(env, napi_value)?,
+                },
+            })
+        }
+    }
+};
+
+unsafe impl<T: ReprC, E: ReprC> ReprC for Result<T, E> {
+    type CLayout = Result_Layout<T::CLayout, E::CLayout>;
+
+    fn is_valid(it: &'_ Self::CLayout) -> bool {
+        // Zero-sized payloads (_e.g._, `()`) are not to be checked.
+        match it.tag {
+            | 0 => mem::size_of::<T>() == 0 || T::is_valid(unsafe { &it.payload.ok }),
+            | 1 => mem::size_of::<E>() == 0 || E::is_valid(unsafe { &it.payload.err }),
+            | _ => false,
+        }
+    }
+
+    fn __canonicalize__(it: &'_ mut Self::CLayout) {
+        match it.tag {
+            | 0 => T::__canonicalize__(unsafe { &mut it.payload.ok }),
+            | _ => E::__canonicalize__(unsafe { &mut it.payload.err }),
+        }
+    }
+}
+
+impl<T, E> From<::core::result::Result<T, E>> for Result<T, E> {
+    fn from(value: ::core::result::Result<T, E>) -> Self {
+        match value {
+            | Ok(v) => Self::Ok(v),
+            | Err(e) => Self::Err(e),
+        }
+    }
+}
+
+impl<T, E> From<Result<T, E>> for ::core::result::Result<T, E> {
+    fn from(value: Result<T, E>) -> Self {
+        match value {
+            | Result::Ok(v) => Ok(v),
+            | Result::Err(e) => Err(e),
+        }
+    }
+}
+
+impl<T, E> Result<T, E> {
+    /// Converts from `&Result<T, E>` to `Result<&T, &E>`.
+    pub fn as_ref(&self) -> ::core::result::Result<&T, &E> {
+        match self {
+            | Self::Ok(v) => Ok(v),
+            | Self::Err(e) => Err(e),
+        }
+    }
+    /// Converts from `&mut Result<T, E>` to `Result<&mut T, &mut E>`.
+    pub fn as_mut(&mut self) -> ::core::result::Result<&mut T, &mut E> {
+        match self {
+            | Self::Ok(v) => Ok(v),
+            | Self::Err(e) => Err(e),
+        }
+    }
+    /// Returns `true` if the result is `Ok`.
+    pub fn is_ok(&self) -> bool {
+        matches!(self, Self::Ok(_))
+    }
+    /// Returns `true` if the result is `Err`.
+    pub fn is_err(&self) -> bool {
+        matches!(self, Self::Err(_))
+    }
+    /// Converts `self` into a standard Rust [Result][::core::result::Result].
+    pub fn into_rust(self) -> ::core::result::Result<T, E> {
+        self.into()
+    }
+}
+
+#[test]
+fn result() {
+    for i in 0..=u8::MAX {
+        let expected = Ok::<u8, u16>(i);
+        let converted = Result::from(expected);
+        let c_layout = unsafe { crate::layout::into_raw(converted) };
+        assert!(Result::<u8, u16>::is_valid(&c_layout));
+        assert_eq!(c_layout.tag, 0);
+        assert_eq!(unsafe { c_layout.payload.ok }, i);
+        assert_eq!(expected, converted.into());
+
+        let expected = Err::<u16, u8>(i);
+        let converted = Result::from(expected);
+        let c_layout = unsafe { crate::layout::into_raw(converted) };
+        assert!(Result::<u16, u8>::is_valid(&c_layout));
+        assert_eq!(c_layout.tag, 1);
+        assert_eq!(unsafe { c_layout.payload.err }, i);
+        assert_eq!(expected, converted.into_rust());
+    }
+    assert!(bool::not(Result::<u8, u8>::is_valid(&Result_Layout {
+        tag: 2,
+        payload: ResultPayload_Layout { ok: 0 },
+    })));
+    assert!(bool::not(Result::<bool, ()>::is_valid(&Result_Layout {
+        tag: 0,
+        payload: ResultPayload_Layout {
+            ok: unsafe { mem::transmute::<u8, crate::layout::impls::Bool>(2) },
+        },
+    })));
+}
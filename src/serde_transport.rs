@@ -0,0 +1,113 @@
+//! Serialized-payload transport for `#[ffi_export(serde = …)]` functions.
+//!
+//! Some Rust types (deeply nested configs, `enum`s with payloads, _etc._) are
+//! not worth a `#[derive_ReprC]` / C-side mirror, and are better shipped
+//! across the FFI boundary in a serialized form. With the `serde = "json"`
+//! (requires the `serde-json` Cargo feature) or `serde = "cbor"` (requires the
+//! `serde-cbor` Cargo feature) parameter, `#[ffi_export]` lowers such a
+//! function as follows:
+//!
+//!   - every parameter `arg: T`, with `T : DeserializeOwned`, becomes a
+//!     (borrowed) byte buffer, a [`c_slice::Ref<'_, u8>`];
+//!
+//!   - the returned value, `T : Serialize`, is returned as an owned byte buffer,
+//!     a <code>[Option]<[c_slice::Box]\<u8\>></code>, which is `NULL` when
+//!     deserializing some parameter (or serializing the returned value) failed;
+//!
+//!   - a `<fname>_free()` function is also exported, to free the returned
+//!     buffers.
+//!
+//! ```rust
+//! # #[cfg(feature = "serde-json")] const _: () = {
+//! use ::safer_ffi::prelude::*;
+//! use ::std::collections::BTreeMap;
+//!
+//! /// Exported as:
+//! /// `slice_boxed_uint8_t total_limit (slice_ref_uint8_t limits);`
+//! /// and `void total_limit_free (slice_boxed_uint8_t ret);`
+//! #[ffi_export(serde = "json")]
+//! fn total_limit(limits: BTreeMap<String, u32>) -> Result<u32, String> {
+//!     limits
+//!         .into_iter()
+//!         .try_fold(0_u32, |acc, (name, limit)| {
+//!             acc.checked_add(limit).ok_or(name)
+//!         })
+//! }
+//! # };
+//! ```
+//!
+//! The generated headers document the expected (Rust) type of each payload.
+
+use_prelude!();
+use ::serde::Serialize;
+use ::serde::de::DeserializeOwned;
+
+/// A serialization format usable with `#[ffi_export(serde = …)]`.
+pub trait Format {
+    fn to_vec<T: ?Sized + Serialize>(value: &'_ T) -> Option<rust::Vec<u8>>;
+
+    fn from_slice<T: DeserializeOwned>(bytes: &'_ [u8]) -> Option<T>;
+}
+
+/// `#[ffi_export(serde = "json")]`.
+#[cfg(feature = "serde-json")]
+#[cfg_attr(all(docs, feature = "nightly"), doc(cfg(feature = "serde-json")))]
+#[allow(missing_debug_implementations)]
+pub enum Json {}
+
+#[cfg(feature = "serde-json")]
+impl Format for Json {
+    fn to_vec<T: ?Sized + Serialize>(value: &'_ T) -> Option<rust::Vec<u8>> {
+        ::serde_json::to_vec(value).ok()
+    }
+
+    fn from_slice<T: DeserializeOwned>(bytes: &'_ [u8]) -> Option<T> {
+        ::serde_json::from_slice(bytes).ok()
+    }
+}
+
+/// `#[ffi_export(serde = "cbor")]`.
+#[cfg(feature = "serde-cbor")]
+#[cfg_attr(all(docs, feature = "nightly"), doc(cfg(feature = "serde-cbor")))]
+#[allow(missing_debug_implementations)]
+pub enum Cbor {}
+
+#[cfg(feature = "serde-cbor")]
+impl Format for Cbor {
+    fn to_vec<T: ?Sized + Serialize>(value: &'_ T) -> Option<rust::Vec<u8>> {
+        let mut ret = rust::Vec::new();
+        ::ciborium::into_writer(value, &mut ret).ok()?;
+        Some(ret)
+    }
+
+    fn from_slice<T: DeserializeOwned>(bytes: &'_ [u8]) -> Option<T> {
+        ::ciborium::from_reader(bytes).ok()
+    }
+}
+
+#[doc(hidden)] /** Not part of the public API! */
+#[inline]
+pub fn __deserialize_arg__<F: Format, T: DeserializeOwned>(
+    arg: c_slice::Ref<'_, u8>,
+) -> Option<T> {
+    F::from_slice(arg.as_slice())
+}
+
+#[doc(hidden)] /** Not part of the public API! */
+#[inline]
+pub fn __serialize_ret__<F: Format, T: Serialize>(ret: &'_ T) -> Option<c_slice::Box<u8>> {
+    F::to_vec(ret).map(|bytes| bytes.into_boxed_slice().into())
+}
+
+#[cfg(feature = "serde-json")]
+#[test]
+fn json_round_trip() {
+    let arg: rust::Vec<(u8, rust::String)> = vec![(42, "safer".into())];
+    let bytes = __serialize_ret__::<Json, _>(&arg).unwrap();
+    assert_eq!(&bytes[..], br#"[[42,"safer"]]"#);
+    assert_eq!(
+        __deserialize_arg__::<Json, rust::Vec<(u8, rust::String)>>(bytes.as_ref()),
+        Some(arg),
+    );
+    assert!(__deserialize_arg__::<Json, u8>(c_slice::Ref::from(&b"-1"[..])).is_none());
+}
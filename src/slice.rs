@@ -10,11 +10,23 @@ pub use self::slice_ref as Ref;
 cfg_alloc! {
     #[doc(no_inline)]
     pub use slice_boxed as Box;
+    #[doc(no_inline)]
+    pub use slice_arc as Arc;
 }
 
 /// The phantoms from the crate are not `ReprC`.
 type PhantomCovariantLifetime<'lt> = PhantomData<&'lt ()>;
 
+/// Whether `len` elements of type `T` span no more than `isize::MAX` bytes,
+/// as required by [`slice::from_raw_parts()`].
+#[inline]
+pub(crate) fn is_valid_len<T>(len: usize) -> bool {
+    match mem::size_of::<T>() {
+        | 0 => true,
+        | elem_size => len <= isize::MAX as usize / elem_size,
+    }
+}
+
 ReprC! {
     #[repr(C, js)]
     /// Like [`slice_ref`] and [`slice_mut`], but with any lifetime attached
@@ -243,43 +255,362 @@ cfg_alloc! {
         where
             rust::Box<[T]> : Sync,
         {}
-}
 
-ReprC! {
-    #[repr(C, js)]
-    /// `&'lt [T]` but with a guaranteed `#[repr(C)]` layout.
-    ///
-    /// # C layout (for some given type T)
-    ///
-    /// ```c
-    /// typedef struct {
-    ///     // Cannot be NULL
-    ///     T * ptr;
-    ///     size_t len;
-    /// } slice_T;
-    /// ```
-    ///
-    /// # Nullable pointer?
-    ///
-    /// If you want to support the above typedef, but where the `ptr` field is
-    /// allowed to be `NULL` (with the contents of `len` then being undefined)
-    /// use the `Option< slice_ptr<_> >` type.
-    pub
-    struct slice_ref['lt, T]
-    where {
-        T : 'lt,
+    #[cfg(feature = "serde")]
+    impl<T : serde::Serialize> serde::Serialize
+        for slice_boxed<T>
+    {
+        fn serialize<S : serde::Serializer> (
+            self: &'_ Self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>
+        {
+            serde::Serialize::serialize(&**self, serializer)
+        }
     }
+
+    #[cfg(feature = "serde")]
+    impl<'de, T : serde::Deserialize<'de>> serde::Deserialize<'de>
+        for slice_boxed<T>
     {
-        /// Pointer to the first element (if any).
-        pub(in crate)
-        ptr: ptr::NonNullRef<T>,
+        fn deserialize<D : serde::Deserializer<'de>> (
+            deserializer: D,
+        ) -> Result<Self, D::Error>
+        {
+            serde::Deserialize::deserialize(deserializer)
+                .map(|vec: rust::Vec<T>| vec.into_boxed_slice().into())
+        }
+    }
 
-        /// Element count
-        pub(in crate)
-        len: usize,
+    ReprC! {
+        #[repr(C)]
+        #[cfg_attr(all(docs, feature = "nightly"), doc(cfg(feature = "alloc")))]
+        /// [`Arc`][`rust::Arc`]`<[T]>` (fat pointer to a shared slice),
+        /// but with a guaranteed `#[repr(C)]` layout.
+        ///
+        /// The reference counts are shared with the [`Arc`][`rust::Arc`]s it
+        /// converts from and to, and [`Clone`] only increments the strong one.
+        ///
+        /// # C layout (for some given type T)
+        ///
+        /// ```c
+        /// typedef struct {
+        ///     // Cannot be NULL
+        ///     T * ptr;
+        ///     size_t len;
+        /// } slice_arc_T;
+        /// ```
+        ///
+        /// Since no retain / release functions are exported for it, C code
+        /// is only to read through `ptr`: it must neither free it, nor keep
+        /// (owning) copies of it, but rather hand the `slice_arc_T` back to
+        /// Rust, which is to drop it.
+        pub
+        struct slice_arc[T] {
+            /// Pointer to the first element (if any).
+            pub(in crate)
+            ptr: ptr::NonNullOwned<T>,
+
+            /// Element count
+            pub(in crate)
+            len: usize,
+        }
+    }
+
+    impl<T> slice_arc<T> {
+        #[inline]
+        pub
+        fn as_ref<'borrow> (self: &'borrow Self)
+          -> slice_ref<'borrow, T>
+        {
+            Into::into(&self[..])
+        }
+
+        #[inline]
+        pub
+        fn as_slice<'borrow> (self: &'borrow Self)
+          -> &'borrow [T]
+        {
+            self.as_ref().as_slice()
+        }
+
+        #[inline]
+        fn as_raw (self: &'_ Self)
+          -> *const [T]
+        {
+            ptr::slice_from_raw_parts(self.ptr.as_ptr(), self.len)
+        }
+
+        /// Morally, a <code>\&[slice_arc\<T\>][`slice_arc`] ->
+        /// \&[Arc\<\[T\]\>][`rust::Arc`]</code> conversion.
+        ///
+        /// For lifetime reasons, this is exposed as a scoped / callback / CPS API.
+        #[inline]
+        pub
+        fn with_rust<R> (
+            self: &'_ Self,
+            scope: impl FnOnce(&rust::Arc<[T]>) -> R,
+        ) -> R
+        {
+            let yield_ = scope;
+            let arc: &rust::Arc<[T]> = &*mem::ManuallyDrop::new(unsafe {
+                rust::Arc::from_raw(self.as_raw())
+            });
+            yield_(arc)
+        }
+    }
+
+    impl<T> From<rust::Arc<[T]>>
+        for slice_arc<T>
+    {
+        #[inline]
+        fn from (arced_slice: rust::Arc<[T]>)
+          -> Self
+        {
+            slice_arc {
+                len: arced_slice.len(),
+                ptr: unsafe {
+                    ptr::NonNull::new_unchecked(
+                        rust::Arc::into_raw(arced_slice).cast::<T>().cast_mut()
+                    )
+                }.into(),
+            }
+        }
+    }
+
+    impl<T> From<slice_arc<T>> for rust::Arc<[T]>
+    {
+        #[inline]
+        fn from (value: slice_arc<T>)
+          -> rust::Arc<[T]>
+        {
+            let this = mem::ManuallyDrop::new(value);
+            unsafe {
+                rust::Arc::from_raw(this.as_raw())
+            }
+        }
+    }
+
+    impl<T> Drop
+        for slice_arc<T>
+    {
+        #[inline]
+        fn drop (self: &'_ mut Self)
+        {
+            unsafe {
+                drop::<rust::Arc<[T]>>(rust::Arc::from_raw(self.as_raw()));
+            }
+        }
+    }
+
+    impl<T> Clone
+        for slice_arc<T>
+    {
+        #[inline]
+        fn clone (self: &'_ slice_arc<T>)
+          -> slice_arc<T>
+        {
+            self.with_rust(rust::Arc::clone).into()
+        }
+    }
+
+    impl<T> Deref
+        for slice_arc<T>
+    {
+        type Target = [T];
+
+        #[inline]
+        fn deref (self: &'_ Self)
+          -> &'_ Self::Target
+        {
+            unsafe {
+                slice::from_raw_parts(self.ptr.as_ptr(), self.len)
+            }
+        }
+    }
+
+    impl<T : fmt::Debug> fmt::Debug
+        for slice_arc<T>
+    {
+        #[inline]
+        fn fmt (self: &'_ Self, fmt: &'_ mut fmt::Formatter<'_>)
+          -> fmt::Result
+        {
+            <[T] as fmt::Debug>::fmt(self, fmt)
+        }
+    }
+
+    unsafe // Safety: equivalent to that of the `where` bound
+        impl<T> Send
+            for slice_arc<T>
+        where
+            rust::Arc<[T]> : Send,
+        {}
+    unsafe // Safety: equivalent to that of the `where` bound
+        impl<T> Sync
+            for slice_arc<T>
+        where
+            rust::Arc<[T]> : Sync,
+        {}
+}
+
+/// The C layouts of [`slice_ref`] and [`slice_mut`]: these `ReprC!` twins
+/// with nullable pointers only serve to define them (and their headers), since
+/// the actual slice types hand-roll their `ReprC` checks.
+mod c_layout {
+    use super::*;
+
+    ReprC! {
+        #[repr(C, js)]
+        /// `&'lt [T]` but with a guaranteed `#[repr(C)]` layout.
+        ///
+        /// # C layout (for some given type T)
+        ///
+        /// ```c
+        /// typedef struct {
+        ///     // NULL only if `len == 0`
+        ///     T * ptr;
+        ///     size_t len;
+        /// } slice_T;
+        /// ```
+        ///
+        /// # Nullable pointer?
+        ///
+        /// If you want to support the above typedef, but where the `ptr` field is
+        /// allowed to be `NULL` (with the contents of `len` then being undefined)
+        /// use the `Option< slice_ptr<_> >` type.
+        pub
+        struct slice_ref['lt, T]
+        where {
+            T : 'lt,
+        }
+        {
+            /// Pointer to the first element (if any).
+            pub
+            ptr: *const T,
+
+            /// Element count
+            pub
+            len: usize,
+
+            pub
+            _lt: PhantomCovariantLifetime<'lt>,
+        }
+    }
+
+    ReprC! {
+        #[repr(C)]
+        /// `&'lt mut [T]` but with a guaranteed `#[repr(C)]` layout.
+        ///
+        /// # C layout (for some given type T)
+        ///
+        /// ```c
+        /// typedef struct {
+        ///     // NULL only if `len == 0`
+        ///     T * ptr;
+        ///     size_t len;
+        /// } slice_T;
+        /// ```
+        ///
+        /// # Nullable pointer?
+        ///
+        /// If you want to support the above typedef, but where the `ptr` field is
+        /// allowed to be `NULL` (with the contents of `len` then being undefined)
+        /// use the `Option< slice_ptr<_> >` type.
+        pub
+        struct slice_mut['lt, T]
+        where {
+            T : 'lt,
+        }
+        {
+            /// Pointer to the first element (if any).
+            pub
+            ptr: *mut T,
+
+            /// Element count
+            pub
+            len: usize,
+
+            pub
+            _lt: PhantomCovariantLifetime<'lt>,
+        }
+    }
+}
+
+pub use c_layout::slice_mut_Layout;
+pub use c_layout::slice_ref_Layout;
+
+/// Whether a `(ptr, len)` pair received from C can be turned into a Rust
+/// slice: `ptr` is to be non-`NULL` and well-aligned, and `len` to span no
+/// more than `isize::MAX` bytes, as [`slice::from_raw_parts()`] requires;
+/// except for the `(NULL, 0)` empty slice.
+#[inline]
+fn is_valid_slice<T>(
+    ptr: *const (),
+    len: usize,
+) -> bool {
+    if ptr.is_null() {
+        len == 0
+    } else {
+        (ptr as usize) % mem::align_of::<T>() == 0 && is_valid_len::<T>(len)
+    }
+}
+
+/// `&'lt [T]` but with a guaranteed `#[repr(C)]` layout.
+///
+/// # C layout (for some given type T)
+///
+/// ```c
+/// typedef struct {
+///     // NULL only if `len == 0`
+///     T * ptr;
+///     size_t len;
+/// } slice_T;
+/// ```
+///
+/// # Validity
+///
+/// When received from C (_e.g._, through [`from_raw`][crate::layout::from_raw]),
+/// `ptr` is checked to be non-`NULL` and well-aligned, and `len` to span no
+/// more than `isize::MAX` bytes, as [`slice::from_raw_parts()`] requires.
+///
+/// A `(NULL, 0)` pair, a common way to express an empty slice in C, is
+/// accepted as well, as the empty slice.
+///
+/// # Nullable pointer?
+///
+/// If you want to support the above typedef, but where the `ptr` field is
+/// allowed to be `NULL` (with the contents of `len` then being undefined)
+/// use the `Option< slice_ptr<_> >` type. Note that a `(NULL, 0)` pair is
+/// then `None`.
+#[cfg_attr(feature = "stabby", stabby::stabby)]
+#[repr(C)]
+pub struct slice_ref<'lt, T>
+where
+    T: 'lt,
+{
+    /// Pointer to the first element (if any).
+    pub(in crate) ptr: ptr::NonNullRef<T>,
+
+    /// Element count
+    pub(in crate) len: usize,
+
+    pub(in crate) _lt: PhantomCovariantLifetime<'lt>,
+}
+
+unsafe impl<'lt, T: 'lt + ReprC> ReprC for slice_ref<'lt, T> {
+    type CLayout = CLayoutOf<c_layout::slice_ref<'lt, T>>;
 
-        pub(in crate)
-        _lt: PhantomCovariantLifetime<'lt>,
+    #[inline]
+    fn is_valid(it: &'_ Self::CLayout) -> bool {
+        is_valid_slice::<T>(it.ptr.cast(), it.len)
+    }
+
+    #[inline]
+    fn __canonicalize__(it: &'_ mut Self::CLayout) {
+        if it.ptr.is_null() {
+            it.ptr = ptr::NonNull::<T>::dangling().as_ptr().cast_const().cast();
+        }
     }
 }
 
@@ -298,6 +629,24 @@ impl<'lt, T: 'lt> slice_ref<'lt, T> {
     pub fn as_slice(self: slice_ref<'lt, T>) -> &'lt [T] {
         unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
     }
+
+    /// Like [`slice::from_raw_parts()`], but for a `ptr` coming from C, for
+    /// which `(NULL, 0)` is accepted as the empty slice.
+    ///
+    /// # Safety
+    ///
+    ///   - Unless `len == 0`, the safety requirements of
+    ///     [`slice::from_raw_parts()`] must hold for the `'lt` lifetime.
+    #[inline]
+    pub unsafe fn from_raw_parts(
+        ptr: *const T,
+        len: usize,
+    ) -> slice_ref<'lt, T> {
+        if ptr.is_null() && len == 0 {
+            return <&[T]>::default().into();
+        }
+        unsafe { slice::from_raw_parts(ptr, len) }.into()
+    }
 }
 
 impl<'lt, T: 'lt> Copy for slice_ref<'lt, T> {}
@@ -339,41 +688,55 @@ impl<'lt, T: 'lt> From<slice_ref<'lt, T>> for slice_raw<T> {
     }
 }
 
-ReprC! {
-    #[repr(C)]
-    /// `&'lt mut [T]` but with a guaranteed `#[repr(C)]` layout.
-    ///
-    /// # C layout (for some given type T)
-    ///
-    /// ```c
-    /// typedef struct {
-    ///     // Cannot be NULL
-    ///     T * ptr;
-    ///     size_t len;
-    /// } slice_T;
-    /// ```
-    ///
-    /// # Nullable pointer?
-    ///
-    /// If you want to support the above typedef, but where the `ptr` field is
-    /// allowed to be `NULL` (with the contents of `len` then being undefined)
-    /// use the `Option< slice_ptr<_> >` type.
-    pub
-    struct slice_mut['lt, T]
-    where {
-        T : 'lt,
-    }
-    {
-        /// Pointer to the first element (if any).
-        pub(in crate)
-        ptr: ptr::NonNullMut<T>,
+/// `&'lt mut [T]` but with a guaranteed `#[repr(C)]` layout.
+///
+/// # C layout (for some given type T)
+///
+/// ```c
+/// typedef struct {
+///     // NULL only if `len == 0`
+///     T * ptr;
+///     size_t len;
+/// } slice_T;
+/// ```
+///
+/// # Validity
+///
+/// Same as for [`slice_ref`].
+///
+/// # Nullable pointer?
+///
+/// If you want to support the above typedef, but where the `ptr` field is
+/// allowed to be `NULL` (with the contents of `len` then being undefined)
+/// use the `Option< slice_ptr<_> >` type.
+#[cfg_attr(feature = "stabby", stabby::stabby)]
+#[repr(C)]
+pub struct slice_mut<'lt, T>
+where
+    T: 'lt,
+{
+    /// Pointer to the first element (if any).
+    pub(in crate) ptr: ptr::NonNullMut<T>,
 
-        /// Element count
-        pub(in crate)
-        len: usize,
+    /// Element count
+    pub(in crate) len: usize,
+
+    pub(in crate) _lt: PhantomCovariantLifetime<'lt>,
+}
+
+unsafe impl<'lt, T: 'lt + ReprC> ReprC for slice_mut<'lt, T> {
+    type CLayout = CLayoutOf<c_layout::slice_mut<'lt, T>>;
 
-        pub(in crate)
-        _lt: PhantomCovariantLifetime<'lt>,
+    #[inline]
+    fn is_valid(it: &'_ Self::CLayout) -> bool {
+        is_valid_slice::<T>(it.ptr.cast(), it.len)
+    }
+
+    #[inline]
+    fn __canonicalize__(it: &'_ mut Self::CLayout) {
+        if it.ptr.is_null() {
+            it.ptr = ptr::NonNull::<T>::dangling().as_ptr().cast();
+        }
     }
 }
 
@@ -643,3 +1006,36 @@ impl<'lt> From<&'lt str> for slice_ref<'lt, u8> {
         s.as_bytes().into()
     }
 }
+
+#[test]
+fn validity() {
+    use crate::layout::from_raw;
+    use crate::layout::into_raw;
+
+    let xs = [1_u32, 2, 3];
+    let valid = unsafe { into_raw(slice_ref::from(&xs[..])) };
+    assert!(unsafe { from_raw::<slice_ref<'_, u32>>(valid) }.is_some());
+
+    let mut overflowing = valid;
+    overflowing.len = usize::MAX / 2;
+    assert!(unsafe { from_raw::<slice_ref<'_, u32>>(overflowing) }.is_none());
+
+    let mut misaligned = valid;
+    misaligned.ptr = misaligned.ptr.cast::<u8>().wrapping_add(1).cast();
+    assert!(unsafe { from_raw::<slice_ref<'_, u32>>(misaligned) }.is_none());
+
+    let mut null = valid;
+    null.ptr = ptr::null();
+    null.len = 0;
+    let empty = unsafe { from_raw::<slice_ref<'_, u32>>(null) }.unwrap();
+    assert!(empty.is_empty());
+    assert!(empty.as_ptr().is_null().not());
+    assert!(unsafe { from_raw::<Option<slice_ref<'_, u32>>>(null) }.unwrap().is_none());
+    null.len = 1;
+    assert!(unsafe { from_raw::<slice_ref<'_, u32>>(null) }.is_none());
+    assert!(unsafe { slice_ref::<u32>::from_raw_parts(ptr::null(), 0) }.is_empty());
+
+    use crate::string::str_ref;
+    assert_eq!(str_ref::from_utf8(b"safer"[..].into()).unwrap().as_str(), "safer");
+    assert!(str_ref::from_utf8(b"\xff"[..].into()).is_err());
+}
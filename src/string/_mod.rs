@@ -6,6 +6,9 @@ use_prelude!();
 pub use slice::*;
 mod slice;
 
+pub use utf16::*;
+mod utf16;
+
 cfg_alloc! {
     use repr_c::Vec;
 
@@ -122,4 +125,26 @@ cfg_alloc! {
             Self(self.0.clone())
         }
     }
+
+    #[cfg(feature = "serde")]
+    impl serde::Serialize for String {
+        fn serialize<S: serde::Serializer> (
+            &self,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>
+        {
+            serializer.serialize_str(self)
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    impl<'de> serde::Deserialize<'de> for String {
+        fn deserialize<D: serde::Deserializer<'de>> (
+            deserializer: D,
+        ) -> Result<Self, D::Error>
+        {
+            serde::Deserialize::deserialize(deserializer)
+                .map(|s: rust::String| s.into())
+        }
+    }
 }
@@ -114,12 +114,136 @@ cfg_alloc! {
             <rust::Box<str>>::into(value.into())
         }
     }
+
+    ReprC! {
+        #[repr(transparent)]
+        #[cfg_attr(all(docs, feature = "nightly"), doc(cfg(feature = "alloc")))]
+        /// Same as [`Arc`][`rust::Arc`]`<str>`, but with a guaranteed
+        /// `#[repr(C)]` layout.
+        ///
+        /// [`Clone`] only increments the (shared) strong count.
+        ///
+        /// As with a [`slice_arc`][crate::slice::slice_arc], C code is only to
+        /// read through it, and to hand it back to Rust to drop it.
+        #[derive(Clone)]
+        pub
+        struct str_arc (
+            slice_arc<u8>,
+        );
+    }
+
+    impl From<rust::Arc<str>>
+        for str_arc
+    {
+        #[inline]
+        fn from (arced_str: rust::Arc<str>)
+          -> str_arc
+        {
+            let arced_bytes: rust::Arc<[u8]> = arced_str.into();
+            Self(arced_bytes.into())
+        }
+    }
+
+    impl From<str_arc> for rust::Arc<str>
+    {
+        #[inline]
+        fn from (value: str_arc)
+          -> rust::Arc<str>
+        {
+            let arced_bytes: rust::Arc<[u8]> = value.0.into();
+            unsafe {
+                // Safety: the bytes are UTF-8 by construction.
+                rust::Arc::from_raw(rust::Arc::into_raw(arced_bytes) as *const str)
+            }
+        }
+    }
+
+    impl<'lt> From<&'lt str>
+        for str_arc
+    {
+        #[inline]
+        fn from (s: &'lt str)
+          -> str_arc
+        {
+            Self::from(rust::Arc::<str>::from(s))
+        }
+    }
+
+    impl From<rust::String>
+        for str_arc
+    {
+        #[inline]
+        fn from (s: rust::String)
+          -> str_arc
+        {
+            Self::from(rust::Arc::<str>::from(s))
+        }
+    }
+
+    impl str_arc {
+        #[inline]
+        pub
+        fn as_ref (self: &'_ str_arc)
+          -> str_ref<'_>
+        {
+            str_ref(self.0.as_ref())
+        }
+
+        /// The underlying shared bytes.
+        #[inline]
+        pub
+        fn into_bytes (self: str_arc)
+          -> slice_arc<u8>
+        {
+            self.0
+        }
+    }
+
+    impl Deref
+        for str_arc
+    {
+        type Target = str;
+
+        #[inline]
+        fn deref (self: &'_ str_arc)
+          -> &'_ str
+        {
+            self.as_ref().as_str()
+        }
+    }
+
+    impl fmt::Debug
+        for str_arc
+    {
+        fn fmt (self: &'_ Self, fmt: &'_ mut fmt::Formatter<'_>)
+          -> fmt::Result
+        {
+            <str as fmt::Debug>::fmt(self, fmt)
+        }
+    }
+
+    impl fmt::Display
+        for str_arc
+    {
+        fn fmt (self: &'_ Self, fmt: &'_ mut fmt::Formatter<'_>)
+          -> fmt::Result
+        {
+            <str as fmt::Display>::fmt(self, fmt)
+        }
+    }
 }
 
 ReprC! {
     #[repr(transparent)]
     #[derive(Clone, Copy)]
     /// `&'lt str`, but with a guaranteed `#[repr(C)]` layout.
+    ///
+    /// # Validity
+    ///
+    /// Only the [`slice_ref`] checks are performed when receiving one from C:
+    /// the bytes are trusted to be valid UTF-8. When that trust is not
+    /// warranted, take a [`slice_ref`]`<'_, u8>` instead, and go through
+    /// [`str_ref::from_utf8()`].
     pub
     struct str_ref['lt,] (
         slice_ref<'lt, u8>,
@@ -135,6 +259,12 @@ impl<'lt> From<&'lt str> for str_ref<'lt> {
 }
 
 impl<'lt> str_ref<'lt> {
+    /// Checked conversion from a byte slice, _e.g._, one received from C.
+    #[inline]
+    pub fn from_utf8(bytes: slice_ref<'lt, u8>) -> Result<str_ref<'lt>, ::core::str::Utf8Error> {
+        ::core::str::from_utf8(bytes.as_slice()).map(Self::from)
+    }
+
     #[inline]
     pub fn as_str(self: str_ref<'lt>) -> &'lt str {
         unsafe {
@@ -0,0 +1,304 @@
+use_prelude!();
+use ::core::slice;
+
+use crate::slice::*;
+
+/// Error for UTF-16 code units which are not a valid (`NUL`-free or
+/// `NUL`-terminated, depending on the target type) UTF-16 string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidUtf16 {
+    /// A `NUL` code unit was not at the expected terminating position.
+    InvalidNulTerminator,
+    /// A surrogate code unit, at the given `index`, was not part of a
+    /// (high, low) surrogate pair.
+    UnpairedSurrogate { index: usize },
+}
+
+impl fmt::Display for InvalidUtf16 {
+    fn fmt(
+        self: &'_ Self,
+        fmt: &'_ mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match *self {
+            | Self::InvalidNulTerminator => {
+                fmt::Display::fmt("Null code unit not at the expected terminating position", fmt)
+            },
+            | Self::UnpairedSurrogate { index } => {
+                write!(fmt, "Unpaired surrogate found at index {index}")
+            },
+        }
+    }
+}
+
+cfg_std! {
+    impl ::std::error::Error for InvalidUtf16 {}
+}
+
+/// Checks that every surrogate code unit in `units` is properly paired.
+pub(crate) fn check_surrogates(units: &'_ [u16]) -> Result<(), InvalidUtf16> {
+    let mut index = 0;
+    for c in char::decode_utf16(units.iter().copied()) {
+        match c {
+            | Ok(c) => index += c.len_utf16(),
+            | Err(_) => return Err(InvalidUtf16::UnpairedSurrogate { index }),
+        }
+    }
+    Ok(())
+}
+
+/// Decodes `units`, replacing unpaired surrogates with
+/// [`char::REPLACEMENT_CHARACTER`].
+pub(crate) fn decode_lossy<'lt>(units: &'lt [u16]) -> impl Iterator<Item = char> + 'lt {
+    char::decode_utf16(units.iter().copied())
+        .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+}
+
+pub(crate) fn fmt_debug(
+    units: &'_ [u16],
+    fmt: &'_ mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    use fmt::Write;
+    fmt.write_char('"')?;
+    for c in decode_lossy(units).flat_map(char::escape_debug) {
+        fmt.write_char(c)?;
+    }
+    fmt.write_char('"')
+}
+
+pub(crate) fn fmt_display(
+    units: &'_ [u16],
+    fmt: &'_ mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    use fmt::Write;
+    decode_lossy(units).try_for_each(|c| fmt.write_char(c))
+}
+
+#[inline]
+pub(crate) fn as_c_char16s(units: &'_ [u16]) -> &'_ [c_char16] {
+    unsafe {
+        // Safety: `c_char16` is a `#[repr(transparent)]` wrapper around `u16`.
+        &*(units as *const [u16] as *const [c_char16])
+    }
+}
+
+ReprC! {
+    #[repr(transparent)]
+    #[derive(Clone, Copy)]
+    /// A `#[repr(C)]` UTF-16 encoded string slice (_fat_ pointer: pointer to
+    /// the `char16_t` code units, and their count), _e.g._, for C#'s
+    /// `string`s or Windows' wide strings.
+    ///
+    /// Since the code units may come from C, they are only checked to be
+    /// well-formed UTF-16 when converting to a Rust string.
+    pub
+    struct str16_ref['lt,] (
+        slice_ref<'lt, c_char16>,
+    );
+}
+
+impl<'lt> TryFrom<&'lt [u16]> for str16_ref<'lt> {
+    type Error = InvalidUtf16;
+
+    fn try_from(units: &'lt [u16]) -> Result<str16_ref<'lt>, InvalidUtf16> {
+        check_surrogates(units)?;
+        Ok(Self(as_c_char16s(units).into()))
+    }
+}
+
+impl<'lt> str16_ref<'lt> {
+    /// The UTF-16 code units of the string.
+    #[inline]
+    pub fn units(self: str16_ref<'lt>) -> &'lt [u16] {
+        unsafe { slice::from_raw_parts(self.0.as_ptr().cast(), self.0.len()) }
+    }
+
+    #[inline]
+    pub fn len(self: str16_ref<'lt>) -> usize {
+        self.0.len()
+    }
+
+    #[inline]
+    pub fn is_empty(self: str16_ref<'lt>) -> bool {
+        self.len() == 0
+    }
+}
+
+impl fmt::Debug for str16_ref<'_> {
+    fn fmt(
+        self: &'_ Self,
+        fmt: &'_ mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        fmt_debug(self.units(), fmt)
+    }
+}
+
+impl fmt::Display for str16_ref<'_> {
+    fn fmt(
+        self: &'_ Self,
+        fmt: &'_ mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        fmt_display(self.units(), fmt)
+    }
+}
+
+impl PartialEq for str16_ref<'_> {
+    #[inline]
+    fn eq(
+        self: &'_ Self,
+        other: &'_ Self,
+    ) -> bool {
+        self.units() == other.units()
+    }
+}
+
+impl Eq for str16_ref<'_> {}
+
+cfg_alloc! {
+    impl<'lt> str16_ref<'lt> {
+        /// Decodes the string, failing on unpaired surrogates.
+        pub
+        fn to_rust_string (self: str16_ref<'lt>)
+          -> Result<rust::String, ::core::char::DecodeUtf16Error>
+        {
+            char::decode_utf16(self.units().iter().copied()).collect()
+        }
+
+        /// Decodes the string, replacing unpaired surrogates with
+        /// [`char::REPLACEMENT_CHARACTER`].
+        pub
+        fn to_string_lossy (self: str16_ref<'lt>)
+          -> rust::String
+        {
+            decode_lossy(self.units()).collect()
+        }
+
+        #[inline]
+        pub
+        fn to_boxed (self: str16_ref<'lt>)
+          -> str16_boxed
+        {
+            str16_boxed::from_units(self.units().into())
+        }
+    }
+
+    ReprC! {
+        #[repr(transparent)]
+        #[cfg_attr(all(docs, feature = "nightly"), doc(cfg(feature = "alloc")))]
+        /// Same as [`str16_ref`], but **owned** / heap-allocated.
+        pub
+        struct str16_boxed (
+            slice_boxed<c_char16>,
+        );
+    }
+
+    impl str16_boxed {
+        #[inline]
+        fn from_units (units: rust::Box<[u16]>)
+          -> str16_boxed
+        {
+            let units: rust::Box<[c_char16]> = unsafe {
+                // Safety: `c_char16` is a `#[repr(transparent)]` wrapper around `u16`.
+                rust::Box::from_raw(rust::Box::into_raw(units) as *mut [c_char16])
+            };
+            Self(units.into())
+        }
+
+        #[inline]
+        pub
+        fn as_str16_ref (self: &'_ str16_boxed)
+          -> str16_ref<'_>
+        {
+            str16_ref(self.0.as_ref())
+        }
+
+        #[inline]
+        pub
+        fn units (self: &'_ str16_boxed)
+          -> &'_ [u16]
+        {
+            self.as_str16_ref().units()
+        }
+
+        #[inline]
+        pub
+        fn to_rust_string (self: &'_ str16_boxed)
+          -> Result<rust::String, ::core::char::DecodeUtf16Error>
+        {
+            self.as_str16_ref().to_rust_string()
+        }
+
+        #[inline]
+        pub
+        fn to_string_lossy (self: &'_ str16_boxed)
+          -> rust::String
+        {
+            self.as_str16_ref().to_string_lossy()
+        }
+    }
+
+    impl<'lt> From<&'lt str>
+        for str16_boxed
+    {
+        #[inline]
+        fn from (s: &'lt str)
+          -> str16_boxed
+        {
+            Self::from_units(s.encode_utf16().collect())
+        }
+    }
+
+    impl From<rust::String>
+        for str16_boxed
+    {
+        #[inline]
+        fn from (s: rust::String)
+          -> str16_boxed
+        {
+            Self::from(&*s)
+        }
+    }
+
+    impl<'lt> TryFrom<&'lt [u16]>
+        for str16_boxed
+    {
+        type Error = InvalidUtf16;
+
+        #[inline]
+        fn try_from (units: &'lt [u16])
+          -> Result<str16_boxed, InvalidUtf16>
+        {
+            str16_ref::try_from(units).map(str16_ref::to_boxed)
+        }
+    }
+
+    impl Clone
+        for str16_boxed
+    {
+        #[inline]
+        fn clone (self: &'_ str16_boxed)
+          -> str16_boxed
+        {
+            self.as_str16_ref().to_boxed()
+        }
+    }
+
+    impl fmt::Debug
+        for str16_boxed
+    {
+        fn fmt (self: &'_ Self, fmt: &'_ mut fmt::Formatter<'_>)
+          -> fmt::Result
+        {
+            fmt::Debug::fmt(&self.as_str16_ref(), fmt)
+        }
+    }
+
+    impl fmt::Display
+        for str16_boxed
+    {
+        fn fmt (self: &'_ Self, fmt: &'_ mut fmt::Formatter<'_>)
+          -> fmt::Result
+        {
+            fmt::Display::fmt(&self.as_str16_ref(), fmt)
+        }
+    }
+}
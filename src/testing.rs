@@ -0,0 +1,204 @@
+//! Helpers to property-test the invariants of [`ReprC`] types.
+//!
+//! The [`CType`] and [`ReprC`] traits come with soundness requirements which
+//! the type system cannot check (_e.g._, [`ReprC::is_valid`] must accept
+//! every valid bit-pattern). Hand-written impls, but also derived ones with
+//! hand-picked field types, can get these wrong, so these helpers are meant
+//! to be called from `#[test]`s to exercise those invariants:
+//!
+//! ```rust
+//! use ::safer_ffi::prelude::*;
+//! use ::safer_ffi::testing;
+//!
+//! #[derive_ReprC]
+//! #[repr(C)]
+//! #[derive(Debug, Clone, PartialEq)]
+//! struct Point {
+//!     x: i32,
+//!     y: i32,
+//! }
+//!
+//! testing::assert_layout::<Point>();
+//! testing::assert_roundtrip(Point { x: 42, y: -27 });
+//! testing::fuzz_is_valid::<Point>(0xdeadbeef, 1_000);
+//! testing::assert_niche_consistency(&42_i32);
+//! ```
+//!
+//! All the helpers panic on failure.
+
+use_prelude!();
+
+use crate::layout::__HasNiche__ as HasNiche;
+use crate::layout::CLayoutOf;
+use crate::layout::TypeDescriptor;
+use crate::layout::TypeKind;
+use crate::layout::{self};
+
+/// Checks that `value` survives a round-trip through its C layout, _i.e._,
+/// through [`into_raw`][layout::into_raw] and then
+/// [`from_raw`][layout::from_raw].
+pub fn assert_roundtrip<T>(value: T)
+where
+    T: ReprC + Clone + PartialEq + ::core::fmt::Debug,
+{
+    let c_layout = unsafe { layout::into_raw(value.clone()) };
+    assert!(
+        T::is_valid(&c_layout),
+        "`{}::is_valid()` rejects the C layout of `{:?}`",
+        ::core::any::type_name::<T>(),
+        value,
+    );
+    let roundtripped = unsafe { layout::from_raw::<T>(c_layout) }.unwrap();
+    assert_eq!(roundtripped, value);
+}
+
+/// Feeds `iterations` pseudo-random bit-patterns of the C layout of `T` to
+/// [`ReprC::is_valid`], returning how many of them were deemed valid.
+///
+/// Given the [`CType`] safety contract, every bit-pattern is a valid
+/// instance of the C layout, so this checks that `is_valid` never panics nor
+/// misbehaves on arbitrary input coming from C. The `seed` makes the run
+/// reproducible.
+///
+/// The random pointers thus fed to `is_valid` are fine, since it is not
+/// allowed to dereference them (see [`ReprC::is_valid`]): an impl crashing
+/// here is an unsound one.
+///
+/// Zero-sized and opaque types, for which `is_valid` is meaningless, are
+/// skipped.
+pub fn fuzz_is_valid<T: ReprC>(
+    seed: u64,
+    iterations: usize,
+) -> usize {
+    let size = ::core::mem::size_of::<CLayoutOf<T>>();
+    if size == 0 || matches!(layout::describe::<T>().kind, TypeKind::Opaque) {
+        return 0;
+    }
+    let mut rng = XorShift64(seed | 1);
+    (0..iterations)
+        .filter(|_| {
+            let mut c_layout = ::core::mem::MaybeUninit::<CLayoutOf<T>>::uninit();
+            let bytes = unsafe {
+                ::core::slice::from_raw_parts_mut(c_layout.as_mut_ptr().cast::<u8>(), size)
+            };
+            bytes.iter_mut().for_each(|b| *b = rng.next() as u8);
+            // SAFETY: every bit-pattern is a valid instance of a `CType`.
+            let c_layout = unsafe { c_layout.assume_init() };
+            T::is_valid(&c_layout)
+        })
+        .count()
+}
+
+/// Checks that [`HasNiche::is_niche`] agrees with the actual niche Rust uses
+/// for `Option<T>`: the C layout of `None` must be a niche (and not a valid
+/// `T`), whereas the one of `Some(value)` must not be.
+pub fn assert_niche_consistency<T: HasNiche>(value: T) {
+    let none = unsafe { layout::into_raw(None::<T>) };
+    assert!(
+        T::is_niche(&none),
+        "`None::<{}>` is not deemed a niche",
+        ::core::any::type_name::<T>(),
+    );
+    assert!(
+        T::is_valid(&none).not(),
+        "`None::<{}>` is deemed a valid `T`",
+        ::core::any::type_name::<T>(),
+    );
+    let some = unsafe { layout::into_raw(Some(value)) };
+    assert!(
+        T::is_niche(&some).not(),
+        "`Some::<{}>(_)` is deemed a niche",
+        ::core::any::type_name::<T>(),
+    );
+    drop(unsafe { layout::from_raw_unchecked::<Option<T>>(some) });
+}
+
+/// Checks that the size and alignment of `T`, and the offsets of its
+/// fields, match the ones a C compiler would compute out of its
+/// [`describe()`][layout::describe]d layout (that is, the one the generated
+/// headers claim), recursively.
+pub fn assert_layout<T: ReprC>() {
+    let ty_name = ::core::any::type_name::<T>();
+    let descriptor = layout::describe::<T>();
+    if matches!(descriptor.kind, TypeKind::Opaque) {
+        return;
+    }
+    let (size, align) = c_size_and_align(&descriptor);
+    assert_eq!(
+        ::core::mem::size_of::<T>(),
+        size,
+        "size mismatch between `{ty_name}` and its C layout",
+    );
+    assert_eq!(
+        ::core::mem::align_of::<T>(),
+        align,
+        "alignment mismatch between `{ty_name}` and its C layout",
+    );
+}
+
+/// The `(size, align)` of the C type described by `descriptor`, as laid out
+/// by the C rules (out of its fields', for compound types), checking that the
+/// actual field offsets agree along the way.
+fn c_size_and_align(descriptor: &'_ TypeDescriptor) -> (usize, usize) {
+    let TypeDescriptor {
+        ref name,
+        size,
+        align,
+        ref kind,
+    } = *descriptor;
+    let round_up = |offset: usize, align: usize| offset.next_multiple_of(align);
+    let (c_size, c_align) = match kind {
+        | TypeKind::Struct { fields } => {
+            let mut c_align = 1;
+            let mut end_of_previous_field = 0;
+            for field in fields {
+                let (field_size, field_align) = c_size_and_align(&field.ty);
+                let field_name = field.name;
+                assert_eq!(
+                    field.offset,
+                    round_up(end_of_previous_field, field_align),
+                    "field `{field_name}` of `{name}` is not where C would put it",
+                );
+                end_of_previous_field = field.offset + field_size;
+                c_align = c_align.max(field_align);
+            }
+            (round_up(end_of_previous_field, c_align), c_align)
+        },
+        | TypeKind::Union { fields } => {
+            let (c_size, c_align) = fields
+                .iter()
+                .map(|field| {
+                    assert_eq!(field.offset, 0, "field `{}` of union `{name}`", field.name);
+                    c_size_and_align(&field.ty)
+                })
+                .fold((0, 1), |(s, a), (field_s, field_a)| (s.max(field_s), a.max(field_a)));
+            (round_up(c_size, c_align), c_align)
+        },
+        | TypeKind::Array { item, len } => {
+            let (item_size, item_align) = c_size_and_align(item);
+            (item_size * len, item_align)
+        },
+        // Scalars: the descriptor is the source of truth.
+        | _ => (size, align),
+    };
+    assert!(c_align.is_power_of_two(), "`{name}` has an invalid alignment");
+    assert_eq!(
+        (size, align),
+        (c_size, c_align),
+        "the (size, align) of `{name}` does not match its C layout",
+    );
+    (c_size, c_align)
+}
+
+/// Tiny, dependency-free, PRNG: <https://en.wikipedia.org/wiki/Xorshift>.
+struct XorShift64(u64);
+
+impl XorShift64 {
+    fn next(self: &'_ mut Self) -> u64 {
+        let Self(ref mut state) = *self;
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+}
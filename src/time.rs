@@ -0,0 +1,192 @@
+//! FFI-safe versions of [`core::time::Duration`] and
+//! [`std::time::SystemTime`].
+
+use_prelude!();
+
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+
+/// A number of nanoseconds, guaranteed to be less than one second, _i.e._,
+/// `< 1_000_000_000`.
+///
+/// Its C layout is that of a `uint32_t`, but for this extra validity
+/// invariant, which is checked when receiving it from C.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Nanos(u32);
+
+impl Nanos {
+    /// Returns `None` if `nanos >= 1_000_000_000`.
+    #[inline]
+    pub const fn new(nanos: u32) -> Option<Nanos> {
+        if nanos < NANOS_PER_SEC {
+            Some(Self(nanos))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub const fn get(self: Nanos) -> u32 {
+        self.0
+    }
+}
+
+unsafe impl ReprC for Nanos {
+    type CLayout = u32;
+
+    #[inline]
+    fn is_valid(&nanos: &'_ u32) -> bool {
+        nanos < NANOS_PER_SEC
+    }
+}
+
+/// An FFI-safe version of [`core::time::Duration`], converting to and from
+/// it when necessary.
+///
+/// Its C layout is that of:
+///
+/// ```C
+/// typedef struct Duration {
+///     uint64_t secs;
+///     /** \brief
+///      *  `< 1_000_000_000`
+///      */
+///     uint32_t nanos;
+/// } Duration_t;
+/// ```
+#[derive_ReprC(js)]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Duration {
+    pub secs: u64,
+    /// `< 1_000_000_000`
+    pub nanos: Nanos,
+}
+
+impl From<::core::time::Duration> for Duration {
+    #[inline]
+    fn from(duration: ::core::time::Duration) -> Duration {
+        Self {
+            secs: duration.as_secs(),
+            nanos: Nanos(duration.subsec_nanos()),
+        }
+    }
+}
+
+impl From<Duration> for ::core::time::Duration {
+    #[inline]
+    fn from(Duration { secs, nanos }: Duration) -> ::core::time::Duration {
+        Self::new(secs, nanos.get())
+    }
+}
+
+impl Duration {
+    /// Converts `self` into a standard Rust [`Duration`][::core::time::Duration].
+    #[inline]
+    pub fn into_rust(self: Duration) -> ::core::time::Duration {
+        self.into()
+    }
+}
+
+/// An FFI-safe version of [`std::time::SystemTime`], converting to and from
+/// it when necessary.
+///
+/// It is represented as the time elapsed since the [UNIX
+/// epoch][::std::time::UNIX_EPOCH], with the `secs` being negative for
+/// points in time before it (`nanos` always counts forward: `-1.5s` is
+/// represented as `{ .secs = -2, .nanos = 500000000 }`):
+///
+/// ```C
+/// typedef struct SystemTime {
+///     int64_t secs;
+///     /** \brief
+///      *  `< 1_000_000_000`
+///      */
+///     uint32_t nanos;
+/// } SystemTime_t;
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(all(docs, feature = "nightly"), doc(cfg(feature = "std")))]
+#[derive_ReprC(js)]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SystemTime {
+    pub secs: i64,
+    /// `< 1_000_000_000`
+    pub nanos: Nanos,
+}
+
+cfg_std! {
+    /// # Panics
+    ///
+    /// If the number of seconds since (or until) the UNIX epoch does not fit
+    /// in an `i64`.
+    impl From<::std::time::SystemTime> for SystemTime {
+        fn from(time: ::std::time::SystemTime) -> SystemTime {
+            const OVERFLOW: &str = "overflow when converting to a `repr_c::SystemTime`";
+            match time.duration_since(::std::time::UNIX_EPOCH) {
+                | Ok(elapsed) => Self {
+                    secs: elapsed.as_secs().try_into().expect(OVERFLOW),
+                    nanos: Nanos(elapsed.subsec_nanos()),
+                },
+                | Err(err) => {
+                    let until = err.duration();
+                    let secs = i64::try_from(until.as_secs()).expect(OVERFLOW);
+                    match until.subsec_nanos() {
+                        | 0 => Self {
+                            secs: -secs,
+                            nanos: Nanos(0),
+                        },
+                        | nanos => Self {
+                            secs: (-secs).checked_sub(1).expect(OVERFLOW),
+                            nanos: Nanos(NANOS_PER_SEC - nanos),
+                        },
+                    }
+                },
+            }
+        }
+    }
+
+    /// # Panics
+    ///
+    /// If the resulting point in time cannot be represented by the
+    /// underlying system.
+    impl From<SystemTime> for ::std::time::SystemTime {
+        fn from(SystemTime { secs, nanos }: SystemTime) -> ::std::time::SystemTime {
+            let epoch = ::std::time::UNIX_EPOCH;
+            let secs_duration = ::core::time::Duration::from_secs(secs.unsigned_abs());
+            let nanos_duration = ::core::time::Duration::from_nanos(nanos.get().into());
+            if secs >= 0 {
+                epoch + secs_duration + nanos_duration
+            } else {
+                epoch - secs_duration + nanos_duration
+            }
+        }
+    }
+
+    impl SystemTime {
+        /// Converts `self` into a standard Rust [`SystemTime`][::std::time::SystemTime].
+        #[inline]
+        pub fn into_rust(self: SystemTime) -> ::std::time::SystemTime {
+            self.into()
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn system_time_roundtrip() {
+    use ::std::time::UNIX_EPOCH;
+
+    let before_epoch = UNIX_EPOCH - ::core::time::Duration::from_millis(1_500);
+    let c_time = SystemTime::from(before_epoch);
+    assert_eq!((c_time.secs, c_time.nanos.get()), (-2, 500_000_000));
+    assert_eq!(c_time.into_rust(), before_epoch);
+
+    let now = ::std::time::SystemTime::now();
+    assert_eq!(SystemTime::from(now).into_rust(), now);
+
+    let duration = ::core::time::Duration::new(42, 999_999_999);
+    assert_eq!(Duration::from(duration).into_rust(), duration);
+    assert!(bool::not(Nanos::is_valid(&NANOS_PER_SEC)));
+}
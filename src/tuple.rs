@@ -18,6 +18,12 @@ pub(crate) use void::CVoid;
 unsafe impl CType for CVoid {
     type OPAQUE_KIND = crate::layout::OpaqueKind::Concrete;
 
+    __cfg_alloc__! {
+        fn describe() -> crate::layout::TypeDescriptor {
+            crate::layout::TypeDescriptor::new::<Self>(crate::layout::TypeKind::Void)
+        }
+    }
+
     __cfg_headers__! {
         fn short_name () -> String {
             "void".into()
@@ -126,3 +132,56 @@ ReprC! {
         pub _5: T5,
     }
 }
+
+#[cfg(feature = "serde")]
+macro_rules! impl_serde_for_tuples {(
+    $(
+        $(#[$meta:meta])*
+        $Tuple:ident [$($T:ident . $field:ident),+ $(,)?]
+    )*
+) => (
+    $(
+        $(#[$meta])*
+        impl<$($T : serde::Serialize),+> serde::Serialize
+            for $Tuple<$($T),+>
+        {
+            fn serialize<S : serde::Serializer> (
+                self: &'_ Self,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error>
+            {
+                serde::Serialize::serialize(&($(&self.$field,)+), serializer)
+            }
+        }
+
+        $(#[$meta])*
+        impl<'de, $($T : serde::Deserialize<'de>),+> serde::Deserialize<'de>
+            for $Tuple<$($T),+>
+        {
+            fn deserialize<D : serde::Deserializer<'de>> (
+                deserializer: D,
+            ) -> Result<Self, D::Error>
+            {
+                let ($($field,)+): ($($T,)+) =
+                    serde::Deserialize::deserialize(deserializer)?
+                ;
+                Ok(Self { $($field),+ })
+            }
+        }
+    )*
+)}
+
+#[cfg(feature = "serde")]
+impl_serde_for_tuples! {
+    #[cfg(not(docs))]
+    Tuple1[T0._0]
+    Tuple2[T0._0, T1._1]
+    #[cfg(not(docs))]
+    Tuple3[T0._0, T1._1, T2._2]
+    #[cfg(not(docs))]
+    Tuple4[T0._0, T1._1, T2._2, T3._3]
+    #[cfg(not(docs))]
+    Tuple5[T0._0, T1._1, T2._2, T3._3, T4._4]
+    #[cfg(not(docs))]
+    Tuple6[T0._0, T1._1, T2._2, T3._3, T4._4, T5._5]
+}
@@ -11,6 +11,7 @@ pub(crate) use ::core::ops::DerefMut;
 pub(crate) use ::core::ops::Not as _;
 
 pub(crate) use crate::c_char;
+pub(crate) use crate::c_char16;
 pub(crate) use crate::layout::macros::*;
 pub(crate) use crate::layout::*;
 pub(crate) use crate::tuple::*;
@@ -44,6 +45,8 @@ pub(crate) mod rust {
     pub(crate) use ::alloc::sync::Arc;
     #[apply(cfg_alloc)]
     pub(crate) use ::alloc::vec::Vec;
+    #[apply(cfg_alloc)]
+    pub(crate) use ::alloc::sync::Weak;
 }
 
 pub(crate) mod ptr {
@@ -17,6 +17,36 @@ ReprC! {
 }
 
 impl<T> Vec<T> {
+    #[inline]
+    pub fn capacity(self: &'_ Self) -> usize {
+        self.cap
+    }
+
+    /// The (uninitialized) memory past `len`, up to `capacity()`, _e.g._, to be written to before
+    /// calling [`Self::set_len()`].
+    #[inline]
+    pub fn spare_capacity_mut(self: &'_ mut Self) -> &'_ mut [mem::MaybeUninit<T>] {
+        unsafe {
+            slice::from_raw_parts_mut(
+                self.ptr.as_mut_ptr().add(self.len).cast(),
+                self.cap - self.len,
+            )
+        }
+    }
+
+    /// # Safety
+    ///
+    /// Same as for [`rust::Vec::set_len()`]: `new_len <= capacity()`, and the elements up to
+    /// `new_len` must have been initialized.
+    #[inline]
+    pub unsafe fn set_len(
+        self: &'_ mut Self,
+        new_len: usize,
+    ) {
+        debug_assert!(new_len <= self.cap);
+        self.len = new_len;
+    }
+
     #[inline]
     pub fn as_ref(self: &'_ Self) -> slice_ref<'_, T> {
         // should optimize to a `transmute_copy`.
@@ -99,6 +129,23 @@ unsafe impl<T> Send for Vec<T> where rust::Vec<T>: Send {}
 
 unsafe impl<T> Sync for Vec<T> where rust::Vec<T>: Sync {}
 
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Vec<T> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&**self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Vec<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        serde::Deserialize::deserialize(deserializer).map(|vec: rust::Vec<T>| vec.into())
+    }
+}
+
 impl<T> Vec<T> {
     pub const EMPTY: Self = Self {
         ptr: ptr::NonNullOwned(ptr::NonNull::dangling(), PhantomData),
@@ -142,3 +189,42 @@ impl<T: fmt::Debug + ReprC> fmt::Debug for Vec<T> {
 macro_rules! c_vec { [$($input:tt)*] => (
     $crate::prelude::repr_c::Vec::from($crate::ඞ::vec![ $($input)* ])
 )}
+
+#[cfg(all(feature = "serde", test))]
+mod tests {
+    use serde_test::Token;
+    use serde_test::assert_de_tokens;
+    use serde_test::assert_ser_tokens;
+    use serde_test::assert_tokens;
+
+    use super::*;
+
+    #[test]
+    fn serde() {
+        let xs: repr_c::Vec<u8> = c_vec![4, 2];
+        let xs_tokens = &[
+            Token::Seq { len: Some(2) },
+            Token::U8(4),
+            Token::U8(2),
+            Token::SeqEnd,
+        ];
+        assert_ser_tokens(&xs, xs_tokens);
+        let boxed_xs: c_slice::Box<u8> = rust::Box::<[u8]>::from(&*xs).into();
+        assert_ser_tokens(&boxed_xs, xs_tokens);
+
+        assert_ser_tokens(&repr_c::String::from("safer"), &[Token::Str("safer")]);
+        assert_tokens(&char_p::new("safer"), &[Token::Str("safer")]);
+        assert_de_tokens(&char_p::new("safer"), &[Token::Str("safer\0")]);
+        assert_ser_tokens(&crate::boxed::ThinBox::new(42_u8), &[Token::U8(42)]);
+
+        assert_tokens(&repr_c::TaggedOption::Some(42_u8), &[Token::Some, Token::U8(42)]);
+        assert_tokens(&repr_c::TaggedOption::<u8>::None, &[Token::None]);
+
+        assert_ser_tokens(&crate::tuple::Tuple2 { _0: 4_u8, _1: 2_u8 }, &[
+            Token::Tuple { len: 2 },
+            Token::U8(4),
+            Token::U8(2),
+            Token::TupleEnd,
+        ]);
+    }
+}
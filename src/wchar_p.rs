@@ -0,0 +1,441 @@
+//! `char16_t *`-compatible strings (slim pointers), for interop with
+//! wide-char APIs, such as C#'s `string`s.
+//
+//! They are the UTF-16 counterpart of [`char_p`][crate::char_p], and thus do
+//! not support inner nulls either. See [`str16`][crate::prelude::str16] for
+//! the length-prefixed (_fat_ pointer) variants.
+
+use_prelude!();
+use ::core::slice;
+
+use crate::string::InvalidUtf16;
+use crate::string::{self};
+
+__cfg_headers__! {
+    use crate::headers::languages::{
+        CSharp,
+        CSharpMarshaler,
+    };
+}
+
+/// A `#[repr(C)]` null-terminated UTF-16 encoded string, for compatibility
+/// with the C `char16_t const *` API, and C#'s `string` (marshalled as an
+/// `LPWStr`).
+///
+/// This is a **borrowed** version, _i.e._, with the semantics of
+/// `&'lt [u16]`, but for it being a _slim_ pointer.
+///
+/// Since the code units may come from C, they are only checked to be
+/// well-formed UTF-16 when converting to a Rust string.
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct wchar_p_ref<'lt>(ptr::NonNullRef<c_char16>, PhantomCovariantLifetime<'lt>);
+
+/// The `CLayout` of [`wchar_p_ref`]: a `char16_t const *`, but for C#, where
+/// it is a `string`.
+#[doc(hidden)]
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct wchar_p_ref_Layout(*const c_char16);
+
+unsafe impl CType for wchar_p_ref_Layout {
+    type OPAQUE_KIND = OpaqueKind::Concrete;
+    __cfg_alloc__! {
+        fn describe() -> TypeDescriptor {
+            TypeDescriptor::new::<Self>(TypeKind::Pointer {
+                mutable: false,
+                pointee: <c_char16 as CType>::describe,
+            })
+        }
+    }
+    __cfg_headers__! {
+        fn short_name() -> String {
+            "char16_const_ptr".into()
+        }
+
+        fn define_self__impl(
+            language: &'_ dyn HeaderLanguage,
+            definer: &'_ mut dyn Definer,
+        ) -> io::Result<()>
+        {
+            <c_char16 as CType>::define_self(language, definer)
+        }
+
+        fn render(
+            out: &'_ mut dyn io::Write,
+            language: &'_ dyn HeaderLanguage,
+        ) -> io::Result<()>
+        {
+            if language.is::<CSharp>() {
+                return write!(out, "string");
+            }
+            const IMMUTABLE: bool = true;
+            language.emit_pointer_ty(
+                language,
+                out,
+                IMMUTABLE,
+                &PhantomData::<c_char16>,
+            )
+        }
+
+        fn metadata() -> &'static dyn Provider {
+            &provide_with(|request| {
+                request.give_if_requested::<CSharpMarshaler>(|| {
+                    CSharpMarshaler("UnmanagedType.LPWStr")
+                });
+            })
+        }
+    }
+}
+
+from_CType_impl_ReprC! { wchar_p_ref_Layout }
+
+unsafe impl ReprC for wchar_p_ref<'_> {
+    type CLayout = wchar_p_ref_Layout;
+
+    #[inline]
+    fn is_valid(&wchar_p_ref_Layout(it): &'_ wchar_p_ref_Layout) -> bool {
+        it.is_null().not() && (it as usize) % ::core::mem::align_of::<c_char16>() == 0
+    }
+}
+
+const NUL: u16 = 0;
+
+impl wchar_p_ref<'static> {
+    pub const EMPTY: Self = unsafe {
+        Self::from_ptr_unchecked(ptr::NonNull::new_unchecked({
+            const IT: &u16 = &NUL;
+            IT as *const u16 as *mut u16
+        }))
+    };
+}
+
+impl<'lt> wchar_p_ref<'lt> {
+    pub const unsafe fn from_ptr_unchecked(ptr: ptr::NonNull<u16>) -> Self {
+        Self(
+            ptr::NonNullRef(ptr.cast()),
+            PhantomCovariantLifetime::<'static>(PhantomData),
+        )
+    }
+
+    /// The UTF-16 code units of the string, without the `NUL` terminator.
+    #[inline]
+    pub fn units(self: wchar_p_ref<'lt>) -> &'lt [u16] {
+        let units = self.units_with_null();
+        &units[.. units.len() - 1]
+    }
+
+    #[inline]
+    pub fn units_with_null(self: wchar_p_ref<'lt>) -> &'lt [u16] {
+        let ptr: *const u16 = self.0.as_ptr().cast();
+        unsafe {
+            let mut len = 0;
+            while *ptr.add(len) != NUL {
+                len += 1;
+            }
+            slice::from_raw_parts(ptr, len + 1)
+        }
+    }
+}
+
+impl<'lt> TryFrom<&'lt [u16]> for wchar_p_ref<'lt> {
+    type Error = InvalidUtf16;
+
+    /// The `units` must end with a (single) `NUL` terminator.
+    fn try_from(units: &'lt [u16]) -> Result<wchar_p_ref<'lt>, InvalidUtf16> {
+        match units.iter().position(|&unit| unit == NUL) {
+            | Some(idx) if idx + 1 == units.len() => {},
+            | _ => return Err(InvalidUtf16::InvalidNulTerminator),
+        }
+        string::check_surrogates(units)?;
+        Ok(unsafe { Self::from_ptr_unchecked(ptr::NonNull::from(&units[0])) })
+    }
+}
+
+impl fmt::Debug for wchar_p_ref<'_> {
+    fn fmt(
+        self: &'_ Self,
+        fmt: &'_ mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        string::fmt_debug(self.units(), fmt)
+    }
+}
+
+impl fmt::Display for wchar_p_ref<'_> {
+    fn fmt(
+        self: &'_ Self,
+        fmt: &'_ mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        string::fmt_display(self.units(), fmt)
+    }
+}
+
+impl PartialEq for wchar_p_ref<'_> {
+    #[inline]
+    fn eq(
+        self: &'_ Self,
+        other: &'_ Self,
+    ) -> bool {
+        self.units() == other.units()
+    }
+}
+
+impl Eq for wchar_p_ref<'_> {}
+
+unsafe impl Send for wchar_p_ref<'_> where for<'lt> &'lt [u16]: Send {}
+
+unsafe impl Sync for wchar_p_ref<'_> where for<'lt> &'lt [u16]: Sync {}
+
+cfg_alloc! {
+    impl<'lt> wchar_p_ref<'lt> {
+        /// Decodes the string, failing on unpaired surrogates.
+        pub
+        fn to_rust_string (self: wchar_p_ref<'lt>)
+          -> Result<rust::String, ::core::char::DecodeUtf16Error>
+        {
+            char::decode_utf16(self.units().iter().copied()).collect()
+        }
+
+        /// Decodes the string, replacing unpaired surrogates with
+        /// [`char::REPLACEMENT_CHARACTER`].
+        pub
+        fn to_string_lossy (self: wchar_p_ref<'lt>)
+          -> rust::String
+        {
+            string::decode_lossy(self.units()).collect()
+        }
+
+        #[inline]
+        pub
+        fn to_boxed (self: wchar_p_ref<'lt>)
+          -> wchar_p_boxed
+        {
+            wchar_p_boxed::from_units_with_null(self.units_with_null().into())
+        }
+    }
+
+    /// Constructs a new `wchar_p::Box` off a Rust string.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the string contains an interior (non-last) `NUL` byte.
+    #[inline]
+    pub
+    fn new<Str : AsRef<str>> (s: Str)
+      -> wchar_p_boxed
+    {
+        let s = s.as_ref();
+        wchar_p_boxed::try_from(s).unwrap_or_else(|_| panic!(concat!(
+            "Error, the string `{:?}` contains an inner nul byte",
+            " and can thus not be converted to a C string without ",
+            "truncating it.",
+        ), s))
+    }
+
+    ReprC! {
+        #[repr(transparent)]
+        /// A `#[repr(c)]` null-terminated UTF-16 encoded string, for
+        /// compatibility with the `char16_t *` C API.
+        ///
+        /// This is an **owned** / heap-allocated version, much like
+        /// `Box<[u16]>` but for it being a _slim_ pointer. Since it has to be
+        /// freed by Rust, it is **not** marshalled as a C# `string`.
+        pub
+        struct wchar_p_boxed (
+            ptr::NonNullOwned<c_char16>,
+        );
+    }
+
+    unsafe // Safety: inherited from `Box<[u16]>`.
+    impl Send
+        for wchar_p_boxed
+    where
+        rust::Box<[u16]> : Send,
+    {}
+
+    unsafe // Safety: inherited from `Box<[u16]>`.
+    impl Sync
+        for wchar_p_boxed
+    where
+        rust::Box<[u16]> : Sync,
+    {}
+
+    impl wchar_p_boxed {
+        /// `units` must be `NUL`-terminated.
+        fn from_units_with_null (units: rust::Box<[u16]>)
+          -> wchar_p_boxed
+        {
+            debug_assert_eq!(units.last(), Some(&NUL));
+            let ptr = rust::Box::into_raw(units) as *mut u16;
+            Self(
+                ptr::NonNullOwned(ptr::NonNull::new(ptr.cast()).unwrap(), PhantomData),
+            )
+        }
+
+        #[inline]
+        pub
+        fn as_wchar_p_ref (self: &'_ wchar_p_boxed)
+          -> wchar_p_ref<'_>
+        {
+            unsafe {
+                mem::transmute(self.0.as_ref())
+            }
+        }
+
+        #[inline]
+        pub
+        fn units (self: &'_ wchar_p_boxed)
+          -> &'_ [u16]
+        {
+            self.as_wchar_p_ref().units()
+        }
+
+        #[inline]
+        pub
+        fn units_with_null (self: &'_ wchar_p_boxed)
+          -> &'_ [u16]
+        {
+            self.as_wchar_p_ref().units_with_null()
+        }
+
+        #[inline]
+        pub
+        fn to_rust_string (self: &'_ wchar_p_boxed)
+          -> Result<rust::String, ::core::char::DecodeUtf16Error>
+        {
+            self.as_wchar_p_ref().to_rust_string()
+        }
+
+        #[inline]
+        pub
+        fn to_string_lossy (self: &'_ wchar_p_boxed)
+          -> rust::String
+        {
+            self.as_wchar_p_ref().to_string_lossy()
+        }
+    }
+
+    impl<'lt> TryFrom<&'lt str>
+        for wchar_p_boxed
+    {
+        type Error = InvalidUtf16;
+
+        /// Fails if the string contains an interior (non-last) `NUL` byte.
+        fn try_from (s: &'lt str)
+          -> Result<wchar_p_boxed, InvalidUtf16>
+        {
+            let mut units: rust::Vec<u16> = s.encode_utf16().collect();
+            match units.iter().position(|&unit| unit == NUL) {
+                | None => {
+                    units.reserve_exact(1);
+                    units.push(NUL);
+                },
+                | Some(idx) if idx + 1 == units.len() => {},
+                | Some(_) => return Err(InvalidUtf16::InvalidNulTerminator),
+            }
+            Ok(Self::from_units_with_null(units.into_boxed_slice()))
+        }
+    }
+
+    impl TryFrom<rust::String>
+        for wchar_p_boxed
+    {
+        type Error = InvalidUtf16;
+
+        #[inline]
+        fn try_from (s: rust::String)
+          -> Result<wchar_p_boxed, InvalidUtf16>
+        {
+            Self::try_from(&*s)
+        }
+    }
+
+    impl<'lt> TryFrom<&'lt [u16]>
+        for wchar_p_boxed
+    {
+        type Error = InvalidUtf16;
+
+        /// The `units` must end with a (single) `NUL` terminator.
+        #[inline]
+        fn try_from (units: &'lt [u16])
+          -> Result<wchar_p_boxed, InvalidUtf16>
+        {
+            wchar_p_ref::try_from(units).map(wchar_p_ref::to_boxed)
+        }
+    }
+
+    impl Drop for wchar_p_boxed {
+        fn drop (self: &'_ mut wchar_p_boxed)
+        {
+            unsafe {
+                let num_units = self.units_with_null().len();
+                drop::<rust::Box<[u16]>>(
+                    rust::Box::from_raw(slice::from_raw_parts_mut(
+                        self.0.as_mut_ptr().cast(),
+                        num_units,
+                    ))
+                );
+            }
+        }
+    }
+
+    impl Clone
+        for wchar_p_boxed
+    {
+        #[inline]
+        fn clone (self: &'_ wchar_p_boxed)
+          -> wchar_p_boxed
+        {
+            self.as_wchar_p_ref().to_boxed()
+        }
+    }
+
+    impl fmt::Debug
+        for wchar_p_boxed
+    {
+        fn fmt (self: &'_ Self, fmt: &'_ mut fmt::Formatter<'_>)
+          -> fmt::Result
+        {
+            fmt::Debug::fmt(&self.as_wchar_p_ref(), fmt)
+        }
+    }
+
+    impl fmt::Display
+        for wchar_p_boxed
+    {
+        fn fmt (self: &'_ Self, fmt: &'_ mut fmt::Formatter<'_>)
+          -> fmt::Result
+        {
+            fmt::Display::fmt(&self.as_wchar_p_ref(), fmt)
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn utf16_strings() {
+    let s = new("héllo 🦀");
+    assert_eq!(s.to_rust_string().unwrap(), "héllo 🦀");
+    assert_eq!(s.units_with_null().last(), Some(&NUL));
+    assert!(wchar_p_boxed::try_from("a\0b").is_err());
+    assert_eq!(wchar_p_ref::EMPTY.units(), &[]);
+
+    let lone_surrogate = [0x61, 0xd800, 0x62, NUL];
+    assert_eq!(
+        wchar_p_ref::try_from(&lone_surrogate[..]),
+        Err(InvalidUtf16::UnpairedSurrogate { index: 1 }),
+    );
+    assert_eq!(
+        wchar_p_ref::try_from(&lone_surrogate[.. 3]),
+        Err(InvalidUtf16::InvalidNulTerminator),
+    );
+    assert_eq!(
+        crate::string::str16_ref::try_from(&lone_surrogate[.. 3]),
+        Err(InvalidUtf16::UnpairedSurrogate { index: 1 }),
+    );
+
+    let s16 = crate::string::str16_boxed::from("🦀\0");
+    assert_eq!(s16.units().len(), 3);
+    assert_eq!(s16.to_rust_string().unwrap(), "🦀\0");
+    assert_eq!(s16.as_str16_ref().to_boxed().to_string_lossy(), "🦀\0");
+}
@@ -0,0 +1,85 @@
+#![cfg(all(feature = "headers", not(target_arch = "wasm32")))]
+#![allow(clippy::all)]
+
+use ::safer_ffi::prelude::*;
+use ::std::io::Write as _;
+use ::std::sync::atomic::AtomicU32;
+use ::std::sync::atomic::AtomicUsize;
+use ::std::sync::atomic::Ordering;
+
+#[derive_ReprC]
+#[repr(C)]
+pub struct Counters {
+    hits: AtomicU32,
+    bytes: AtomicUsize,
+}
+
+#[ffi_export]
+fn counters_hit(counters: &Counters, bytes: usize) {
+    counters.hits.fetch_add(1, Ordering::Relaxed);
+    counters.bytes.fetch_add(bytes, Ordering::Relaxed);
+}
+
+#[test]
+fn atomics_header() {
+    let counters = Counters {
+        hits: 0.into(),
+        bytes: 0.into(),
+    };
+    counters_hit(&counters, 42);
+    assert_eq!(counters.hits.load(Ordering::Relaxed), 1);
+    assert_eq!(counters.bytes.load(Ordering::Relaxed), 42);
+
+    let mut header = vec![];
+    ::safer_ffi::headers::builder()
+        .to_writer(&mut header)
+        .generate()
+        .unwrap();
+    let header = String::from_utf8(header).unwrap();
+    for expected in [
+        "#ifdef __cplusplus\n\
+        extern \"C++\" {\n\
+        #include <atomic>\n\
+        }\n\
+        #define SAFER_FFI_ATOMIC(T) std::atomic<T>\n\
+        #else\n\
+        #define SAFER_FFI_ATOMIC(T) _Atomic(T)\n\
+        #endif\n",
+        "    SAFER_FFI_ATOMIC(uint32_t) hits;",
+        "    SAFER_FFI_ATOMIC(size_t) bytes;",
+    ] {
+        assert!(header.contains(expected), "missing `{expected}` in:\n{header}");
+    }
+
+    // When a C (resp. C++) compiler is available, check that the header is
+    // valid C11 (resp. C++11), with the expected layout in both cases.
+    let dir = ::std::env::temp_dir().join(format!("safer_ffi-atomics-{}", ::std::process::id()));
+    ::std::fs::create_dir_all(&dir).unwrap();
+    ::std::fs::write(dir.join("atomics.h"), &header).unwrap();
+    for (compiler, lang, std, static_assert) in [
+        ("cc", "c", "-std=c11", "_Static_assert"),
+        ("c++", "c++", "-std=c++11", "static_assert"),
+    ] {
+        let Ok(mut child) = ::std::process::Command::new(compiler)
+            .args([std, "-fsyntax-only", "-x", lang, "-I"])
+            .arg(&dir)
+            .arg("-")
+            .stdin(::std::process::Stdio::piped())
+            .spawn()
+        else {
+            eprintln!("`{compiler}` not found: skipping its header check");
+            continue;
+        };
+        write!(
+            child.stdin.take().unwrap(),
+            "#include \"atomics.h\"\n\
+            {static_assert}(sizeof(Counters_t) == {size}, \"size\");\n\
+            {static_assert}(offsetof(Counters_t, bytes) == {offset}, \"offset\");\n",
+            size = ::core::mem::size_of::<Counters>(),
+            offset = ::core::mem::offset_of!(Counters, bytes),
+        )
+        .unwrap();
+        assert!(child.wait().unwrap().success(), "`{compiler}` rejected:\n{header}");
+    }
+    ::std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,74 @@
+#![cfg(all(feature = "headers", not(target_arch = "wasm32")))]
+#![allow(clippy::all)]
+
+use ::safer_ffi::c_alloc::{self, AllocatorVTable, CAlloc, RegisteredAlloc};
+use ::safer_ffi::prelude::*;
+use ::std::ops::Not as _;
+
+::safer_ffi::ffi_export_register_allocator!();
+
+/// To be `free()`d by the caller.
+#[ffi_export]
+fn get_greeting() -> c_alloc::String<CAlloc> {
+    "Hello, World!".into()
+}
+
+/// Returns `NULL` if there is no answer, else a pointer to be `free()`d.
+#[ffi_export]
+fn find_answer(found: bool) -> Option<c_alloc::Box<u32, CAlloc>> {
+    found.then(|| c_alloc::Box::new(42))
+}
+
+#[ffi_export]
+fn get_numbers() -> c_alloc::Vec<u32, RegisteredAlloc> {
+    vec![27, 42].into()
+}
+
+unsafe extern "C" fn c_alloc_(
+    size: usize,
+    _align: usize,
+) -> *mut ::std::ffi::c_void {
+    unsafe { ::libc::malloc(size).cast() }
+}
+
+unsafe extern "C" fn c_free(ptr: *mut ::std::ffi::c_void) {
+    unsafe { ::libc::free(ptr.cast()) }
+}
+
+#[test]
+fn c_alloc_exports() {
+    let vtable = AllocatorVTable {
+        alloc: c_alloc_,
+        free: c_free,
+    };
+    assert!(unsafe { c_alloc::register_allocator(vtable) });
+    assert!(unsafe { c_alloc::register_allocator(vtable) }.not());
+    assert_eq!(&*get_greeting(), "Hello, World!");
+    assert_eq!(get_numbers()[..], [27, 42]);
+    assert_eq!(find_answer(true).as_deref(), Some(&42));
+    assert!(find_answer(false).is_none());
+    ::safer_ffi::testing::assert_niche_consistency(c_alloc::Box::<u32, CAlloc>::new(42));
+
+    for language in [
+        ::safer_ffi::headers::Language::C,
+        ::safer_ffi::headers::Language::CSharp,
+        ::safer_ffi::headers::Language::Lua,
+    ] {
+        let mut header = vec![];
+        ::safer_ffi::headers::builder()
+            .with_language(language)
+            .to_writer(&mut header)
+            .generate()
+            .unwrap();
+        let header = String::from_utf8(header).unwrap();
+        for expected in [
+            "register_allocator",
+            "find_answer",
+            "The returned value is to be freed with `free()`.",
+            "The returned value is to be freed with \
+            the `free` function of the registered `AllocatorVTable`.",
+        ] {
+            assert!(header.contains(expected), "missing `{expected}` in:\n{header}");
+        }
+    }
+}
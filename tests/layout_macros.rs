@@ -221,6 +221,51 @@ fn test_niche() {
     })));
 }
 
+#[test]
+fn test_describe() {
+    use ::safer_ffi::layout::TypeKind;
+
+    let descriptor = ::safer_ffi::layout::describe::<Foo<'static>>();
+    assert_eq!(descriptor.size, ::core::mem::size_of::<Foo_Layout<'static>>());
+    let TypeKind::Struct { fields } = descriptor.kind else {
+        panic!("expected a struct, got {:?}", descriptor.kind);
+    };
+    assert_eq!(fields[0].name, "b");
+    assert_eq!(fields[0].offset, 0);
+    let TypeKind::Enum { variants } = &fields[0].ty.kind else {
+        panic!("expected an enum, got {:?}", fields[0].ty.kind);
+    };
+    assert_eq!(variants[0].discriminant, 42);
+    assert_eq!(variants[1].discriminant, 43);
+    assert_eq!(fields[1].name, "field");
+    assert_eq!(fields[1].offset, 8);
+    assert!(matches!(fields[1].ty.kind, TypeKind::Struct { .. }));
+}
+
+#[test]
+fn test_testing_helpers() {
+    use ::safer_ffi::testing;
+
+    testing::assert_layout::<Foo<'static>>();
+    testing::assert_layout::<repr_c::Vec<u16>>();
+    testing::assert_layout::<repr_c::ArrayVec<u32, 3>>();
+    testing::assert_roundtrip(c_slice::Ref::from(&[1_u16, 2, 3][..]));
+    testing::assert_roundtrip(char_p::new("Hello, World!"));
+    testing::assert_niche_consistency(&42_i32);
+    testing::assert_niche_consistency(c_slice::Ref::from(&[42_i32][..]));
+
+    // Only two valid discriminants out of 256.
+    let accepted = testing::fuzz_is_valid::<MyBool>(0x5eed, 1_000);
+    assert!(accepted < 100, "{accepted}");
+    assert_eq!(testing::fuzz_is_valid::<u32>(0x5eed, 100), 100);
+    // Random pointers: `is_valid` must not dereference them.
+    testing::fuzz_is_valid::<char_p::Array<'static>>(0x5eed, 1_000);
+    testing::fuzz_is_valid::<char_p::Ref<'static>>(0x5eed, 1_000);
+    testing::fuzz_is_valid::<c_slice::Ref<'static, u32>>(0x5eed, 1_000);
+    testing::fuzz_is_valid::<path::Ref<'static>>(0x5eed, 1_000);
+    testing::fuzz_is_valid::<repr_c::PathBuf>(0x5eed, 1_000);
+}
+
 #[test]
 fn test_c_str_macro() {
     let mut it: char_p::Ref<'static> = c!();
@@ -229,6 +274,92 @@ fn test_c_str_macro() {
     assert_eq!(it.to_str(), "Hello, World!");
 }
 
+#[cfg(feature = "headers")]
+#[test]
+fn test_rename_generic() {
+    #[derive_ReprC(rename_generic = "{T}_pair_{N}")]
+    #[repr(C)]
+    pub struct Pair<T, const N: usize> {
+        fst: T,
+        snd: [T; N],
+    }
+
+    assert_eq!(
+        <::safer_ffi::layout::CLayoutOf<Pair<u8, 2>> as CType>::short_name(),
+        format!("{}_pair_2", <u8 as CType>::short_name()),
+    );
+}
+
+#[cfg(feature = "headers")]
+#[test]
+fn test_ffi_renames() {
+    use ::safer_ffi::headers::languages::EnumVariant;
+    use ::safer_ffi::headers::languages::VariantNaming;
+
+    #[derive_ReprC]
+    #[repr(C)]
+    pub struct RenamedPoint {
+        #[ffi(rename = "X")]
+        x: f64,
+        y: f64,
+    }
+
+    #[derive_ReprC(variant_naming = "screaming")]
+    #[repr(u8)]
+    pub enum RenamedLogLevel {
+        #[ffi(rename = "LOG_DISABLED")]
+        Off,
+        Error,
+    }
+
+    #[ffi_export]
+    fn renamed_items(
+        point: RenamedPoint,
+        level: RenamedLogLevel,
+    ) -> f64 {
+        let _ = level;
+        point.x + point.y
+    }
+
+    assert_eq!(
+        renamed_items(RenamedPoint { x: 27., y: 15. }, RenamedLogLevel::Error),
+        42.
+    );
+    let _ = RenamedLogLevel::Off;
+
+    let mut header = vec![];
+    ::safer_ffi::headers::builder()
+        .to_writer(&mut header)
+        .generate()
+        .unwrap();
+    let header = String::from_utf8(header).unwrap();
+    for expected in [
+        "typedef struct RenamedPoint {",
+        "    double X;",
+        "    double y;",
+        "enum RenamedLogLevel {",
+        "    LOG_DISABLED,",
+        "    ERROR,",
+    ] {
+        assert!(header.contains(expected), "missing `{expected}` in:\n{header}");
+    }
+
+    let variant = |name, naming| EnumVariant {
+        docs: &[],
+        name,
+        discriminant: None,
+        naming,
+    };
+    for (name, naming, expected) in [
+        ("SomeVariant", VariantNaming::ScreamingPrefixed, "MY_ENUM_SOME_VARIANT"),
+        ("SomeVariant", VariantNaming::Screaming, "SOME_VARIANT"),
+        ("SomeVariant", VariantNaming::Prefixed, "MyEnum_SomeVariant"),
+        ("LOG_DISABLED", VariantNaming::Verbatim, "LOG_DISABLED"),
+    ] {
+        assert_eq!(variant(name, naming).global_name("MyEnum"), expected);
+    }
+}
+
 #[cfg(feature = "headers")]
 #[test]
 fn generate_headers() -> ::std::io::Result<()> {
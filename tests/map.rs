@@ -0,0 +1,59 @@
+#![cfg(feature = "std")]
+#![allow(clippy::all)]
+
+use ::safer_ffi::prelude::*;
+use ::std::ops::Not as _;
+
+::safer_ffi::ffi_export_map_helpers! {
+    int_map: HashMap<i32, u64>
+}
+
+#[test]
+fn maps() {
+    use ::safer_ffi::map::KeyValue;
+
+    type IntMap = repr_c::HashMap<i32, u64>;
+
+    unsafe extern "C" {
+        fn int_map_new() -> repr_c::Box<IntMap>;
+        fn int_map_free(map: repr_c::Box<IntMap>);
+        fn int_map_len(map: &'_ IntMap) -> usize;
+        fn int_map_get<'map>(
+            map: &'map IntMap,
+            key: &'_ i32,
+        ) -> Option<&'map u64>;
+        fn int_map_insert(
+            map: &'_ mut IntMap,
+            key: i32,
+            value: u64,
+        ) -> bool;
+        fn int_map_remove(
+            map: &'_ mut IntMap,
+            key: &'_ i32,
+        ) -> bool;
+    }
+
+    unsafe {
+        let mut map = int_map_new();
+        assert_eq!(int_map_len(&map), 0);
+        assert!(int_map_insert(&mut map, 42, 27).not());
+        assert!(int_map_insert(&mut map, 42, 28));
+        assert!(int_map_insert(&mut map, 0, 0).not());
+        assert_eq!(int_map_get(&map, &42), Some(&28));
+        assert_eq!(int_map_get(&map, &1), None);
+        assert!(int_map_remove(&mut map, &0));
+        assert!(int_map_remove(&mut map, &0).not());
+        assert_eq!(int_map_len(&map), 1);
+        let entries = map.entries();
+        assert_eq!(&entries[..], [KeyValue { key: &42, value: &28 }]);
+        drop(entries);
+        int_map_free(map);
+    }
+
+    let entries: repr_c::Vec<KeyValue<u8, u32>> =
+        vec![KeyValue { key: 1, value: 10 }, (0, 20).into()].into();
+    let map = repr_c::BTreeMap::from(entries);
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [0, 1]);
+    let map: ::std::collections::BTreeMap<u8, u32> = map.into();
+    assert_eq!(map[&1], 10);
+}
@@ -0,0 +1,45 @@
+#![cfg(all(feature = "headers", feature = "serde-json", feature = "serde-cbor"))]
+#![allow(clippy::all)]
+
+use ::safer_ffi::prelude::*;
+use ::std::collections::BTreeMap;
+
+/// Sums the limits.
+#[ffi_export(serde = "json")]
+fn total_limit_json(limits: BTreeMap<String, u32>) -> Option<u32> {
+    limits
+        .into_values()
+        .try_fold(0_u32, |acc, limit| acc.checked_add(limit))
+}
+
+#[ffi_export(serde = "cbor", rename = "total_limit_cbor")]
+fn total_limit_cbor_(limits: BTreeMap<String, u32>) -> Option<u32> {
+    total_limit_json(limits)
+}
+
+#[test]
+fn serde_exports() {
+    let json = total_limit_json__serde__(c_slice::Ref::from(&br#"{"a":1,"b":41}"#[..]));
+    assert_eq!(json.as_deref(), Some(&b"42"[..]));
+    total_limit_json_free__serde__(json);
+    assert!(total_limit_json__serde__(c_slice::Ref::from(&b"{"[..])).is_none());
+
+    let mut header = vec![];
+    ::safer_ffi::headers::builder()
+        .to_writer(&mut header)
+        .generate()
+        .unwrap();
+    let header = String::from_utf8(header).unwrap();
+    for expected in [
+        "slice_boxed_uint8_t\ntotal_limit_json (\n    slice_ref_uint8_t limits);",
+        "void\ntotal_limit_json_free (\n    slice_boxed_uint8_t ret);",
+        "slice_boxed_uint8_t\ntotal_limit_cbor (\n    slice_ref_uint8_t limits);",
+        "void\ntotal_limit_cbor_free (\n    slice_boxed_uint8_t ret);",
+        " *  # Serialized transport (JSON)",
+        " *  # Serialized transport (CBOR)",
+        " *  - `limits`: `BTreeMap<String, u32>`",
+        " *  Returns a JSON-serialized `Option<u32>`, to be freed with `total_limit_json_free()`",
+    ] {
+        assert!(header.contains(expected), "missing `{expected}` in:\n{header}");
+    }
+}